@@ -264,7 +264,10 @@ export class CarbonVerificationClient {
   async initializeVerifier(
     verifierName: string,
     certificationLevel: string,
-    accreditationBody: string
+    accreditationBody: PublicKey,
+    accreditedSectors: string[],
+    accreditedUntil: number,
+    verifierSeed?: PublicKey
   ): Promise<string> {
     if (!this.program || !this.provider) {
       throw new Error('Client not initialized');
@@ -272,7 +275,7 @@ export class CarbonVerificationClient {
 
     try {
       const [verifierPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("verifier"), this.provider.wallet.publicKey.toBuffer()],
+        [Buffer.from("verifier"), (verifierSeed ?? this.provider.wallet.publicKey).toBuffer()],
         CARBON_VERIFICATION_PROGRAM_ID
       );
 
@@ -288,7 +291,12 @@ export class CarbonVerificationClient {
     projectKey: PublicKey,
     verificationType: string,
     documentationUri: string,
-    estimatedCredits: number
+    estimatedCredits: number,
+    coVerifiers: PublicKey[] = [],
+    requiredSubmissions: number = 1,
+    aggregation: 'minimum' | 'median' = 'minimum',
+    feeAmount: number = 0,
+    feeMint?: PublicKey
   ): Promise<string> {
     if (!this.program || !this.provider) {
       throw new Error('Client not initialized');
@@ -327,7 +335,8 @@ export class CarbonRegistryClient {
 
   async initializeRegistry(
     registryName: string,
-    baseUri: string
+    baseUri: string,
+    registrySeed?: PublicKey
   ): Promise<string> {
     if (!this.program || !this.provider) {
       throw new Error('Client not initialized');
@@ -335,7 +344,7 @@ export class CarbonRegistryClient {
 
     try {
       const [registryPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("registry"), this.provider.wallet.publicKey.toBuffer()],
+        [Buffer.from("registry"), (registrySeed ?? this.provider.wallet.publicKey).toBuffer()],
         CARBON_REGISTRY_PROGRAM_ID
       );
 
@@ -347,22 +356,22 @@ export class CarbonRegistryClient {
     }
   }
 
-  async registerProject(
+  async submitProjectApplication(
     projectId: string,
     vintageYear: number,
-    methodology: string,
+    methodology: PublicKey,
     countryCode: string,
-    projectDeveloper: PublicKey
+    documentationUris: string[]
   ): Promise<string> {
     if (!this.program || !this.provider) {
       throw new Error('Client not initialized');
     }
 
     try {
-      const tx = "demo_project_registration_signature";
+      const tx = "demo_project_application_signature";
       return tx;
     } catch (error) {
-      console.error('Error registering project:', error);
+      console.error('Error submitting project application:', error);
       throw error;
     }
   }
@@ -370,7 +379,9 @@ export class CarbonRegistryClient {
   async issueCredits(
     serialNumberPrefix: string,
     quantity: number,
-    issuanceDate: number
+    issuanceDate: number,
+    vintageStart: number,
+    vintageEnd: number
   ): Promise<string> {
     if (!this.program || !this.provider) {
       throw new Error('Client not initialized');
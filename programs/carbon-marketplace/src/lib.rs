@@ -15,6 +15,7 @@ pub mod carbon_marketplace {
     ) -> Result<()> {
         let marketplace = &mut ctx.accounts.marketplace;
         marketplace.authority = ctx.accounts.authority.key();
+        marketplace.pending_authority = None;
         marketplace.fee_percentage = fee_percentage;
         marketplace.min_credit_amount = min_credit_amount;
         marketplace.total_credits_traded = 0;
@@ -32,6 +33,35 @@ pub mod carbon_marketplace {
         Ok(())
     }
 
+    pub fn propose_marketplace_authority(
+        ctx: Context<ProposeMarketplaceAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let marketplace = &mut ctx.accounts.marketplace;
+        marketplace.pending_authority = Some(new_authority);
+
+        emit!(MarketplaceAuthorityProposed {
+            current_authority: marketplace.authority,
+            pending_authority: new_authority,
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_marketplace_authority(ctx: Context<AcceptMarketplaceAuthority>) -> Result<()> {
+        let marketplace = &mut ctx.accounts.marketplace;
+        let old_authority = marketplace.authority;
+        marketplace.authority = ctx.accounts.new_authority.key();
+        marketplace.pending_authority = None;
+
+        emit!(MarketplaceAuthorityAccepted {
+            old_authority,
+            new_authority: marketplace.authority,
+        });
+
+        Ok(())
+    }
+
     pub fn create_carbon_project(
         ctx: Context<CreateCarbonProject>,
         project_id: String,
@@ -227,6 +257,30 @@ pub struct InitializeMarketplace<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct ProposeMarketplaceAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        has_one = authority @ ErrorCode::UnauthorizedAuthority
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptMarketplaceAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"marketplace"],
+        bump = marketplace.bump,
+        constraint = marketplace.pending_authority == Some(new_authority.key()) @ ErrorCode::NotPendingAuthority
+    )]
+    pub marketplace: Account<'info, Marketplace>,
+    pub new_authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 #[instruction(project_id: String)]
 pub struct CreateCarbonProject<'info> {
@@ -310,6 +364,7 @@ pub struct RetireCredits<'info> {
 #[derive(InitSpace)]
 pub struct Marketplace {
     pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
     pub fee_percentage: u16,
     pub min_credit_amount: u64,
     pub total_credits_traded: u64,
@@ -427,6 +482,18 @@ pub struct MarketplaceInitialized {
     pub min_credit_amount: u64,
 }
 
+#[event]
+pub struct MarketplaceAuthorityProposed {
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct MarketplaceAuthorityAccepted {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
 #[event]
 pub struct CarbonProjectCreated {
     pub project_id: String,
@@ -490,4 +557,8 @@ pub enum ErrorCode {
     ListingExpired,
     #[msg("Retirement reason too long")]
     RetirementReasonTooLong,
+    #[msg("Signer is not the marketplace authority")]
+    UnauthorizedAuthority,
+    #[msg("Signer is not the pending marketplace authority")]
+    NotPendingAuthority,
 }
\ No newline at end of file
@@ -1,30 +1,61 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo};
+use anchor_lang::system_program;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, Burn, FreezeAccount, ThawAccount, CloseAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{self, Metadata, CreateMetadataAccountsV3};
+use mpl_token_metadata::state::DataV2;
+use carbon_verification::{AccreditationBody, VerificationConfig, VerificationResult, Verifier};
 
 declare_id!("CarbRegistry1111111111111111111111111111111");
 
+mod iso3166;
+
+const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+// Leaves room for `/retirements/<retirement>.json` within Metaplex's 200-char URI limit
+const MAX_BASE_URI_LEN: usize = 200 - "/retirements/.json".len() - 44;
+
 #[program]
 pub mod carbon_registry {
     use super::*;
 
     pub fn initialize_registry(
         ctx: Context<InitializeRegistry>,
+        registry_seed: Pubkey,
         registry_name: String,
         base_uri: String,
     ) -> Result<()> {
         require!(registry_name.len() <= 64, ErrorCode::RegistryNameTooLong);
-        require!(base_uri.len() <= 200, ErrorCode::BaseUriTooLong);
+        require!(base_uri.len() <= MAX_BASE_URI_LEN, ErrorCode::BaseUriTooLong);
 
         let registry = &mut ctx.accounts.registry;
         registry.authority = ctx.accounts.authority.key();
+        registry.pending_authority = None;
+        registry.registry_seed = registry_seed;
+        registry.application_deposit = 0;
+        registry.forfeited_deposits = 0;
+        registry.transfer_approval_required = false;
+        registry.share_of_proceeds_bps = 0;
+        registry.omge_bps = 0;
+        registry.adaptation_fund = None;
         registry.registry_name = registry_name.clone();
         registry.base_uri = base_uri;
         registry.total_credits_issued = 0;
         registry.total_credits_retired = 0;
+        registry.total_share_of_proceeds = 0;
+        registry.total_omge_cancelled = 0;
         registry.total_projects = 0;
         registry.created_at = Clock::get()?.unix_timestamp;
         registry.bump = *ctx.bumps.get("registry").unwrap();
 
+        let admin_role = &mut ctx.accounts.admin_role;
+        admin_role.registry = registry.key();
+        admin_role.holder = registry.authority;
+        admin_role.role = Role::Admin;
+        admin_role.granted_by = registry.authority;
+        admin_role.granted_at = registry.created_at;
+        admin_role.bump = *ctx.bumps.get("admin_role").unwrap();
+
         emit!(RegistryInitialized {
             registry_id: registry.key(),
             authority: registry.authority,
@@ -34,40 +65,463 @@ pub mod carbon_registry {
         Ok(())
     }
 
-    pub fn register_project(
-        ctx: Context<RegisterProject>,
+    pub fn propose_registry_authority(
+        ctx: Context<ProposeRegistryAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+        registry.pending_authority = Some(new_authority);
+
+        emit!(RegistryAuthorityProposed {
+            registry_id: registry.key(),
+            current_authority: registry.authority,
+            pending_authority: new_authority,
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_registry_authority(ctx: Context<AcceptRegistryAuthority>) -> Result<()> {
+        // A new authority that already holds Admin passes its existing role instead of creating one
+        require!(
+            ctx.accounts.admin_role.is_some() != ctx.accounts.existing_admin_role.is_some(),
+            ErrorCode::InvalidAdminRole
+        );
+
+        let registry = &mut ctx.accounts.registry;
+        let old_authority = registry.authority;
+        registry.authority = ctx.accounts.new_authority.key();
+        registry.pending_authority = None;
+
+        if let Some(admin_role) = &mut ctx.accounts.admin_role {
+            admin_role.registry = registry.key();
+            admin_role.holder = registry.authority;
+            admin_role.role = Role::Admin;
+            admin_role.granted_by = old_authority;
+            admin_role.granted_at = Clock::get()?.unix_timestamp;
+            admin_role.bump = *ctx.bumps.get("admin_role").unwrap();
+        }
+
+        // The outgoing authority's admin role goes with the registry, unless another admin already revoked it
+        if old_authority != registry.authority && !ctx.accounts.old_admin_role.data_is_empty() {
+            let old_admin_role = ctx.accounts.old_admin_role.to_account_info();
+            let old_authority_info = ctx.accounts.old_authority.to_account_info();
+            **old_authority_info.try_borrow_mut_lamports()? += old_admin_role.lamports();
+            **old_admin_role.try_borrow_mut_lamports()? = 0;
+            old_admin_role.assign(&system_program::ID);
+            old_admin_role.realloc(0, false)?;
+
+            emit!(RoleRevoked {
+                registry: registry.key(),
+                holder: old_authority,
+                role: Role::Admin,
+                revoked_by: registry.authority,
+            });
+        }
+
+        emit!(RegistryAuthorityAccepted {
+            registry_id: registry.key(),
+            old_authority,
+            new_authority: registry.authority,
+        });
+
+        Ok(())
+    }
+
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        holder: Pubkey,
+        role: Role,
+    ) -> Result<()> {
+        let role_assignment = &mut ctx.accounts.role_assignment;
+        role_assignment.registry = ctx.accounts.registry.key();
+        role_assignment.holder = holder;
+        role_assignment.role = role.clone();
+        role_assignment.granted_by = ctx.accounts.admin.key();
+        role_assignment.granted_at = Clock::get()?.unix_timestamp;
+        role_assignment.bump = *ctx.bumps.get("role_assignment").unwrap();
+
+        emit!(RoleGranted {
+            registry: role_assignment.registry,
+            holder,
+            role,
+            granted_by: role_assignment.granted_by,
+        });
+
+        Ok(())
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>) -> Result<()> {
+        let role_assignment = &ctx.accounts.role_assignment;
+        require!(
+            !(role_assignment.role == Role::Admin && role_assignment.holder == ctx.accounts.admin.key()),
+            ErrorCode::CannotRevokeOwnAdmin
+        );
+
+        emit!(RoleRevoked {
+            registry: role_assignment.registry,
+            holder: role_assignment.holder,
+            role: role_assignment.role.clone(),
+            revoked_by: ctx.accounts.admin.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn add_methodology(
+        ctx: Context<AddMethodology>,
+        methodology_id: String,
+        version: String,
+        standard: CarbonStandard,
+        sector: ProjectType,
+        min_crediting_period_years: u8,
+        max_crediting_period_years: u8,
+        max_crediting_period_renewals: u8,
+    ) -> Result<()> {
+        require!(methodology_id.len() <= 32, ErrorCode::MethodologyTooLong);
+        require!(version.len() <= 16, ErrorCode::MethodologyVersionTooLong);
+        require!(
+            min_crediting_period_years > 0 && min_crediting_period_years <= max_crediting_period_years,
+            ErrorCode::InvalidCreditingPeriodLimits
+        );
+
+        let methodology = &mut ctx.accounts.methodology;
+        methodology.registry = ctx.accounts.registry.key();
+        methodology.methodology_id = methodology_id.clone();
+        methodology.version = version.clone();
+        methodology.standard = standard;
+        methodology.sector = sector;
+        methodology.min_crediting_period_years = min_crediting_period_years;
+        methodology.max_crediting_period_years = max_crediting_period_years;
+        methodology.max_crediting_period_renewals = max_crediting_period_renewals;
+        methodology.status = MethodologyStatus::Active;
+        methodology.created_at = Clock::get()?.unix_timestamp;
+        methodology.bump = *ctx.bumps.get("methodology").unwrap();
+
+        emit!(MethodologyAdded {
+            methodology: methodology.key(),
+            methodology_id,
+            version,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_methodology_status(
+        ctx: Context<SetMethodologyStatus>,
+        status: MethodologyStatus,
+    ) -> Result<()> {
+        let methodology = &mut ctx.accounts.methodology;
+        methodology.status = status.clone();
+
+        emit!(MethodologyStatusUpdated {
+            methodology: methodology.key(),
+            status,
+            updated_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn initialize_country_stats(
+        ctx: Context<InitializeCountryStats>,
+        country_code: [u8; 2],
+    ) -> Result<()> {
+        require!(
+            iso3166::country_alpha2(&country_code) == Some(country_code),
+            ErrorCode::InvalidCountryCode
+        );
+
+        let country_stats = &mut ctx.accounts.country_stats;
+        country_stats.registry = ctx.accounts.registry.key();
+        country_stats.country_code = country_code;
+        country_stats.total_projects = 0;
+        country_stats.total_issued = 0;
+        country_stats.total_retired = 0;
+        country_stats.designated_national_authority = None;
+        country_stats.bump = *ctx.bumps.get("country_stats").unwrap();
+
+        Ok(())
+    }
+
+    pub fn set_designated_national_authority(
+        ctx: Context<SetDesignatedNationalAuthority>,
+        designated_national_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.country_stats.designated_national_authority = designated_national_authority;
+        Ok(())
+    }
+
+    pub fn authorize_article6(
+        ctx: Context<AuthorizeArticle6>,
+        authorized_uses: Vec<AuthorizedUse>,
+    ) -> Result<()> {
+        require!(
+            !authorized_uses.is_empty() && authorized_uses.len() <= 3,
+            ErrorCode::InvalidAuthorizedUses
+        );
+
+        let project_registry = &mut ctx.accounts.project_registry;
+        require!(project_registry.article6.is_none(), ErrorCode::AlreadyAuthorized);
+        project_registry.article6 = Some(Article6Authorization {
+            authorized_uses: authorized_uses.clone(),
+            authorized_by: ctx.accounts.authority.key(),
+            authorized_at: Clock::get()?.unix_timestamp,
+            corresponding_adjustment_year: None,
+            first_transferred_at: None,
+            first_transfer_record: None,
+        });
+
+        emit!(Article6Authorized {
+            project_registry_id: project_registry.key(),
+            country_code: project_registry.country_code,
+            authorized_uses,
+            authorized_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn record_corresponding_adjustment(
+        ctx: Context<RecordCorrespondingAdjustment>,
+        reporting_year: u16,
+    ) -> Result<()> {
+        let project_registry = &mut ctx.accounts.project_registry;
+        let project_registry_id = project_registry.key();
+        let article6 = project_registry.article6.as_mut().ok_or(ErrorCode::NotArticle6Authorized)?;
+        article6.corresponding_adjustment_year = Some(reporting_year);
+
+        emit!(CorrespondingAdjustmentRecorded {
+            project_registry_id,
+            reporting_year,
+            recorded_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn set_application_deposit(
+        ctx: Context<SetApplicationDeposit>,
+        application_deposit: u64,
+    ) -> Result<()> {
+        ctx.accounts.registry.application_deposit = application_deposit;
+
+        emit!(ApplicationDepositUpdated {
+            registry_id: ctx.accounts.registry.key(),
+            application_deposit,
+            updated_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_forfeited_deposits(
+        ctx: Context<WithdrawForfeitedDeposits>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidQuantity);
+        let registry = &mut ctx.accounts.registry;
+        require!(amount <= registry.forfeited_deposits, ErrorCode::InsufficientForfeitedDeposits);
+        registry.forfeited_deposits -= amount;
+
+        **registry.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        emit!(ForfeitedDepositsWithdrawn {
+            registry_id: registry.key(),
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+            withdrawn_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn set_issuance_levies(
+        ctx: Context<SetIssuanceLevies>,
+        share_of_proceeds_bps: u16,
+        omge_bps: u16,
+        adaptation_fund: Option<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            share_of_proceeds_bps as u32 + omge_bps as u32 <= 10000,
+            ErrorCode::InvalidLevyPercentage
+        );
+        require!(
+            share_of_proceeds_bps == 0 || adaptation_fund.is_some(),
+            ErrorCode::AdaptationFundRequired
+        );
+
+        let registry = &mut ctx.accounts.registry;
+        registry.share_of_proceeds_bps = share_of_proceeds_bps;
+        registry.omge_bps = omge_bps;
+        registry.adaptation_fund = adaptation_fund;
+
+        emit!(IssuanceLeviesConfigured {
+            registry_id: registry.key(),
+            share_of_proceeds_bps,
+            omge_bps,
+            adaptation_fund,
+        });
+
+        Ok(())
+    }
+
+    pub fn submit_project_application(
+        ctx: Context<SubmitProjectApplication>,
         project_id: String,
         vintage_year: u16,
-        methodology: String,
         country_code: String,
-        project_developer: Pubkey,
+        subdivision_code: Option<String>,
+        project_type: ProjectType,
+        documentation_uris: Vec<String>,
+        crediting_period_start: i64,
+        crediting_period_years: u8,
     ) -> Result<()> {
         require!(project_id.len() <= 32, ErrorCode::ProjectIdTooLong);
-        require!(methodology.len() <= 100, ErrorCode::MethodologyTooLong);
-        require!(country_code.len() <= 3, ErrorCode::CountryCodeTooLong);
-        require!(vintage_year >= 2000 && vintage_year <= 2100, ErrorCode::InvalidVintageYear);
+        let country = iso3166::country_alpha2(country_code.as_bytes()).ok_or(ErrorCode::InvalidCountryCode)?;
+        let subdivision = match subdivision_code {
+            Some(code) => iso3166::subdivision(&country, &code).ok_or(ErrorCode::InvalidSubdivisionCode)?,
+            None => [0u8; 3],
+        };
+        require!((2000..=2100).contains(&vintage_year), ErrorCode::InvalidVintageYear);
+        require!(documentation_uris.len() <= 5, ErrorCode::TooManyDocumentationUris);
+        require!(
+            documentation_uris.iter().all(|uri| uri.len() <= 200),
+            ErrorCode::DocumentationUriTooLong
+        );
+        require!(
+            ctx.accounts.methodology.status == MethodologyStatus::Active,
+            ErrorCode::MethodologyDeprecated
+        );
+        require!(
+            ctx.accounts.methodology.sector == project_type,
+            ErrorCode::MethodologySectorMismatch
+        );
+        require!(
+            ctx.accounts.methodology.allows_crediting_period(crediting_period_years),
+            ErrorCode::InvalidCreditingPeriod
+        );
+        let crediting_period_end = crediting_period_end(crediting_period_start, crediting_period_years)?;
+        require!(
+            (calendar_year(crediting_period_start)..=calendar_year(crediting_period_end - 1))
+                .contains(&(vintage_year as i64)),
+            ErrorCode::VintageOutsideCreditingPeriod
+        );
+
+        let deposit = ctx.accounts.registry.application_deposit;
+        if deposit > 0 {
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.developer.to_account_info(),
+                    to: ctx.accounts.project_application.to_account_info(),
+                },
+            );
+            system_program::transfer(transfer_ctx, deposit)?;
+        }
+
+        let application = &mut ctx.accounts.project_application;
+        application.registry = ctx.accounts.registry.key();
+        application.developer = ctx.accounts.developer.key();
+        application.project_id = project_id.clone();
+        application.vintage_year = vintage_year;
+        application.methodology = ctx.accounts.methodology.key();
+        application.country_code = country;
+        application.subdivision_code = subdivision;
+        application.project_type = project_type;
+        application.documentation_uris = documentation_uris;
+        application.crediting_period_start = crediting_period_start;
+        application.crediting_period_years = crediting_period_years;
+        application.deposit = deposit;
+        application.submitted_at = Clock::get()?.unix_timestamp;
+        application.bump = *ctx.bumps.get("project_application").unwrap();
+
+        emit!(ProjectApplicationSubmitted {
+            application_id: application.key(),
+            registry: application.registry,
+            developer: application.developer,
+            project_id,
+            deposit,
+        });
+
+        Ok(())
+    }
+
+    pub fn approve_project_application(ctx: Context<ApproveProjectApplication>) -> Result<()> {
+        require!(
+            ctx.accounts.methodology.status == MethodologyStatus::Active,
+            ErrorCode::MethodologyDeprecated
+        );
+        let application = &ctx.accounts.project_application;
 
         let project_registry = &mut ctx.accounts.project_registry;
-        project_registry.project_id = project_id.clone();
-        project_registry.vintage_year = vintage_year;
-        project_registry.methodology = methodology;
-        project_registry.country_code = country_code;
-        project_registry.project_developer = project_developer;
+        project_registry.project_id = application.project_id.clone();
+        project_registry.vintage_year = application.vintage_year;
+        project_registry.methodology = application.methodology;
+        project_registry.country_code = application.country_code;
+        project_registry.subdivision_code = application.subdivision_code;
+        project_registry.project_developer = application.developer;
+        project_registry.project_type = application.project_type.clone();
         project_registry.registry = ctx.accounts.registry.key();
+        project_registry.credit_mint = ctx.accounts.credit_mint.key();
         project_registry.total_issued = 0;
         project_registry.total_retired = 0;
+        project_registry.total_reversed = 0;
+        project_registry.pending_developer = None;
+        project_registry.pending_transfer_approved = false;
         project_registry.status = ProjectRegistryStatus::Active;
+        project_registry.status_change_count = 0;
+        project_registry.crediting_period_start = application.crediting_period_start;
+        project_registry.crediting_period_end =
+            crediting_period_end(application.crediting_period_start, application.crediting_period_years)?;
+        project_registry.crediting_period_renewals = 0;
+        project_registry.article6 = None;
         project_registry.registered_at = Clock::get()?.unix_timestamp;
         project_registry.bump = *ctx.bumps.get("project_registry").unwrap();
 
         let registry = &mut ctx.accounts.registry;
         registry.total_projects += 1;
+        ctx.accounts.country_stats.total_projects += 1;
+
+        // The application account is closed to the developer, refunding the deposit
+        emit!(ProjectApplicationApproved {
+            application_id: application.key(),
+            project_registry_id: project_registry.key(),
+            approved_by: ctx.accounts.authority.key(),
+        });
 
         emit!(ProjectRegistered {
             project_registry_id: project_registry.key(),
-            project_id,
-            vintage_year,
-            project_developer,
+            project_id: project_registry.project_id.clone(),
+            vintage_year: project_registry.vintage_year,
+            project_developer: project_registry.project_developer,
+            project_type: project_registry.project_type.clone(),
+        });
+
+        Ok(())
+    }
+
+    pub fn reject_project_application(
+        ctx: Context<RejectProjectApplication>,
+        reason: String,
+    ) -> Result<()> {
+        require!(reason.len() <= 200, ErrorCode::RejectionReasonTooLong);
+
+        // Forfeit the deposit to the registry; the remaining rent goes back to the developer on close
+        let deposit = ctx.accounts.project_application.deposit;
+        if deposit > 0 {
+            **ctx.accounts.project_application.to_account_info().try_borrow_mut_lamports()? -= deposit;
+            **ctx.accounts.registry.to_account_info().try_borrow_mut_lamports()? += deposit;
+            ctx.accounts.registry.forfeited_deposits += deposit;
+        }
+
+        emit!(ProjectApplicationRejected {
+            application_id: ctx.accounts.project_application.key(),
+            developer: ctx.accounts.project_application.developer,
+            forfeited_deposit: deposit,
+            rejected_by: ctx.accounts.authority.key(),
+            reason,
         });
 
         Ok(())
@@ -78,21 +532,85 @@ pub mod carbon_registry {
         serial_number_prefix: String,
         quantity: u64,
         issuance_date: i64,
+        vintage_start: i64,
+        vintage_end: i64,
     ) -> Result<()> {
         require!(serial_number_prefix.len() <= 20, ErrorCode::SerialNumberPrefixTooLong);
         require!(quantity > 0, ErrorCode::InvalidQuantity);
         require!(issuance_date <= Clock::get()?.unix_timestamp, ErrorCode::InvalidIssuanceDate);
+        require!(
+            ctx.accounts.project_registry.status != ProjectRegistryStatus::UnderReview,
+            ErrorCode::ProjectUnderReview
+        );
+        require!(
+            ctx.accounts.project_registry.status == ProjectRegistryStatus::Active,
+            ErrorCode::ProjectNotActive
+        );
+        require!(vintage_end >= vintage_start, ErrorCode::InvalidVintagePeriod);
+        require!(
+            ctx.accounts.project_registry.covers_vintage(vintage_start, vintage_end),
+            ErrorCode::VintageOutsideCreditingPeriod
+        );
 
         let issuance = &mut ctx.accounts.credit_issuance;
         issuance.project_registry = ctx.accounts.project_registry.key();
         issuance.serial_number_prefix = serial_number_prefix.clone();
         issuance.quantity = quantity;
         issuance.issuance_date = issuance_date;
+        issuance.vintage_start = vintage_start;
+        issuance.vintage_end = vintage_end;
         issuance.issued_to = ctx.accounts.recipient.key();
         issuance.status = IssuanceStatus::Active;
+        issuance.cancelled_quantity = 0;
+        issuance.cancellation_reason = None;
+        issuance.cancelled_at = None;
+        issuance.article6_authorized = ctx.accounts.project_registry.article6.is_some();
         issuance.created_at = Clock::get()?.unix_timestamp;
         issuance.bump = *ctx.bumps.get("credit_issuance").unwrap();
 
+        // Withhold the pool's risk percentage for projects exposed to reversals
+        let buffer_percentage = match &ctx.accounts.buffer_pool {
+            Some(buffer_pool) => buffer_pool.buffer_percentage,
+            None => {
+                require!(
+                    !ctx.accounts.project_registry.project_type.has_reversal_risk(),
+                    ErrorCode::BufferPoolRequired
+                );
+                0
+            }
+        };
+        let buffer_contribution = quantity
+            .checked_mul(buffer_percentage as u64)
+            .ok_or(ErrorCode::MathOverflow)?
+            / 10000;
+
+        // Share of proceeds goes to the adaptation fund; the OMGE share is cancelled and never minted
+        let share_of_proceeds = quantity
+            .checked_mul(ctx.accounts.registry.share_of_proceeds_bps as u64)
+            .ok_or(ErrorCode::MathOverflow)?
+            / 10000;
+        let omge_cancelled = quantity
+            .checked_mul(ctx.accounts.registry.omge_bps as u64)
+            .ok_or(ErrorCode::MathOverflow)?
+            / 10000;
+        let withheld = buffer_contribution
+            .checked_add(share_of_proceeds)
+            .and_then(|withheld| withheld.checked_add(omge_cancelled))
+            .ok_or(ErrorCode::MathOverflow)?;
+        let recipient_quantity = quantity
+            .checked_sub(withheld)
+            .ok_or(ErrorCode::LeviesExceedIssuance)?;
+        issuance.buffer_contribution = buffer_contribution;
+        issuance.share_of_proceeds = share_of_proceeds;
+        issuance.omge_cancelled = omge_cancelled;
+
+        let registry_seeds = &[
+            b"registry",
+            ctx.accounts.registry.registry_seed.as_ref(),
+            &[ctx.accounts.registry.bump],
+        ];
+        let signer_seeds = &[&registry_seeds[..]];
+
         // Mint tokens to represent carbon credits
         let mint_to_ctx = CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -102,28 +620,147 @@ pub mod carbon_registry {
                 authority: ctx.accounts.registry.to_account_info(),
             },
         );
+        token::mint_to(mint_to_ctx.with_signer(signer_seeds), recipient_quantity)?;
+
+        if buffer_contribution > 0 {
+            let buffer_vault = ctx.accounts.buffer_vault.as_ref().ok_or(ErrorCode::BufferVaultRequired)?;
+            let buffer_mint_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.credit_mint.to_account_info(),
+                    to: buffer_vault.to_account_info(),
+                    authority: ctx.accounts.registry.to_account_info(),
+                },
+            );
+            token::mint_to(buffer_mint_ctx.with_signer(signer_seeds), buffer_contribution)?;
+
+            if let Some(buffer_pool) = &mut ctx.accounts.buffer_pool {
+                buffer_pool.total_contributed += buffer_contribution;
+            }
+        }
+
+        if share_of_proceeds > 0 {
+            let adaptation_fund_token_account = ctx
+                .accounts
+                .adaptation_fund_token_account
+                .as_ref()
+                .ok_or(ErrorCode::AdaptationFundRequired)?;
+            let share_mint_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.credit_mint.to_account_info(),
+                    to: adaptation_fund_token_account.to_account_info(),
+                    authority: ctx.accounts.registry.to_account_info(),
+                },
+            );
+            token::mint_to(share_mint_ctx.with_signer(signer_seeds), share_of_proceeds)?;
+        }
+
+        // Issued totals are net of cancellations, so the OMGE share is left out
+        let net_issued = quantity - omge_cancelled;
+        let project_registry = &mut ctx.accounts.project_registry;
+        project_registry.total_issued += net_issued;
+
+        let registry = &mut ctx.accounts.registry;
+        registry.total_credits_issued += net_issued;
+        registry.total_share_of_proceeds += share_of_proceeds;
+        registry.total_omge_cancelled += omge_cancelled;
+        ctx.accounts.country_stats.total_issued += net_issued;
+
+        emit!(CreditsIssued {
+            issuance_id: issuance.key(),
+            project_registry: project_registry.key(),
+            serial_number_prefix,
+            quantity,
+            recipient: issuance.issued_to,
+            buffer_contribution,
+            share_of_proceeds,
+            omge_cancelled,
+        });
+
+        Ok(())
+    }
+
+    pub fn cancel_issuance(
+        ctx: Context<CancelIssuance>,
+        quantity: u64,
+        reason: String,
+    ) -> Result<()> {
+        require!(quantity > 0, ErrorCode::InvalidQuantity);
+        require!(reason.len() <= 200, ErrorCode::ReasonTooLong);
+
+        let issuance = &mut ctx.accounts.credit_issuance;
+        require!(issuance.status == IssuanceStatus::Active, ErrorCode::IssuanceNotActive);
+        let outstanding = issuance
+            .quantity
+            .checked_sub(issuance.omge_cancelled)
+            .and_then(|remaining| remaining.checked_sub(issuance.cancelled_quantity))
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(quantity <= outstanding, ErrorCode::CancellationExceedsIssuance);
+        // Reversals may already have written part of the project's issuance off the totals
+        require!(
+            quantity <= ctx.accounts.project_registry.total_issued,
+            ErrorCode::CancellationExceedsIssuance
+        );
+
+        // The registry can only burn from accounts it owns or has been approved as delegate on
+        let source = &ctx.accounts.source_token_account;
+        let registry_key = ctx.accounts.registry.key();
+        require!(
+            source.owner == registry_key
+                || (source.delegate == COption::Some(registry_key) && source.delegated_amount >= quantity),
+            ErrorCode::RegistryCannotBurn
+        );
 
         let registry_seeds = &[
             b"registry",
-            ctx.accounts.registry.authority.as_ref(),
+            ctx.accounts.registry.registry_seed.as_ref(),
             &[ctx.accounts.registry.bump],
         ];
         let signer_seeds = &[&registry_seeds[..]];
 
-        token::mint_to(mint_to_ctx.with_signer(signer_seeds), quantity)?;
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.credit_mint.to_account_info(),
+                from: ctx.accounts.source_token_account.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::burn(burn_ctx.with_signer(signer_seeds), quantity)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        issuance.cancelled_quantity += quantity;
+        issuance.cancellation_reason = Some(reason.clone());
+        issuance.cancelled_at = Some(now);
+        if issuance.cancelled_quantity + issuance.omge_cancelled == issuance.quantity {
+            issuance.status = IssuanceStatus::Cancelled;
+        }
 
         let project_registry = &mut ctx.accounts.project_registry;
-        project_registry.total_issued += quantity;
+        project_registry.total_issued = project_registry
+            .total_issued
+            .checked_sub(quantity)
+            .ok_or(ErrorCode::MathOverflow)?;
 
         let registry = &mut ctx.accounts.registry;
-        registry.total_credits_issued += quantity;
-
-        emit!(CreditsIssued {
+        registry.total_credits_issued = registry
+            .total_credits_issued
+            .checked_sub(quantity)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let country_stats = &mut ctx.accounts.country_stats;
+        country_stats.total_issued = country_stats
+            .total_issued
+            .checked_sub(quantity)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(IssuanceCancelled {
             issuance_id: issuance.key(),
             project_registry: project_registry.key(),
-            serial_number_prefix,
             quantity,
-            recipient: issuance.issued_to,
+            remaining: outstanding - quantity,
+            reason,
+            cancelled_by: ctx.accounts.authority.key(),
         });
 
         Ok(())
@@ -133,9 +770,20 @@ pub mod carbon_registry {
         ctx: Context<TransferCredits>,
         quantity: u64,
         transfer_reason: String,
+        destination_country: Option<[u8; 2]>,
     ) -> Result<()> {
         require!(quantity > 0, ErrorCode::InvalidQuantity);
         require!(transfer_reason.len() <= 200, ErrorCode::TransferReasonTooLong);
+        require!(
+            ctx.accounts.project_registry.status == ProjectRegistryStatus::Active,
+            ErrorCode::ProjectNotActive
+        );
+
+        // Authorized credits must declare where they go so the first international transfer is caught
+        require!(
+            ctx.accounts.project_registry.article6.is_none() || destination_country.is_some(),
+            ErrorCode::DestinationCountryRequired
+        );
 
         let transfer_record = &mut ctx.accounts.transfer_record;
         transfer_record.from_owner = ctx.accounts.from_owner.key();
@@ -143,9 +791,34 @@ pub mod carbon_registry {
         transfer_record.project_registry = ctx.accounts.project_registry.key();
         transfer_record.quantity = quantity;
         transfer_record.transfer_reason = transfer_reason.clone();
+        transfer_record.destination_country = destination_country;
         transfer_record.transferred_at = Clock::get()?.unix_timestamp;
         transfer_record.bump = *ctx.bumps.get("transfer_record").unwrap();
 
+        if let Some(destination) = destination_country {
+            require!(
+                iso3166::country_alpha2(&destination) == Some(destination),
+                ErrorCode::InvalidCountryCode
+            );
+            let project_registry = &mut ctx.accounts.project_registry;
+            if destination != project_registry.country_code {
+                let project_registry_id = project_registry.key();
+                if let Some(article6) = project_registry.article6.as_mut() {
+                    if article6.first_transferred_at.is_none() {
+                        article6.first_transferred_at = Some(transfer_record.transferred_at);
+                        article6.first_transfer_record = Some(transfer_record.key());
+
+                        emit!(FirstTransferRecorded {
+                            project_registry_id,
+                            record: transfer_record.key(),
+                            destination_country: Some(destination),
+                            recorded_at: transfer_record.transferred_at,
+                        });
+                    }
+                }
+            }
+        }
+
         // Transfer tokens
         let transfer_ctx = CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -174,10 +847,27 @@ pub mod carbon_registry {
         quantity: u64,
         retirement_reason: String,
         beneficiary: String,
+        beneficiary_wallet: Option<Pubkey>,
+        use_type: RetirementUse,
     ) -> Result<()> {
         require!(quantity > 0, ErrorCode::InvalidQuantity);
         require!(retirement_reason.len() <= 200, ErrorCode::RetirementReasonTooLong);
         require!(beneficiary.len() <= 100, ErrorCode::BeneficiaryTooLong);
+        require!(
+            ctx.accounts.project_registry.status == ProjectRegistryStatus::Active,
+            ErrorCode::ProjectNotActive
+        );
+        require!(
+            ctx.accounts.certificate_holder.key() == beneficiary_wallet.unwrap_or(ctx.accounts.owner.key()),
+            ErrorCode::InvalidCertificateHolder
+        );
+        if let Some(authorized_use) = use_type.authorized_use() {
+            let article6 = ctx.accounts.project_registry.article6.as_ref().ok_or(ErrorCode::NotArticle6Authorized)?;
+            require!(
+                article6.authorized_uses.contains(&authorized_use),
+                ErrorCode::UseNotAuthorized
+            );
+        }
 
         let retirement = &mut ctx.accounts.credit_retirement;
         retirement.owner = ctx.accounts.owner.key();
@@ -185,9 +875,29 @@ pub mod carbon_registry {
         retirement.quantity = quantity;
         retirement.retirement_reason = retirement_reason.clone();
         retirement.beneficiary = beneficiary;
+        retirement.beneficiary_wallet = beneficiary_wallet;
+        retirement.attribution_transferred = false;
+        retirement.use_type = use_type.clone();
         retirement.retired_at = Clock::get()?.unix_timestamp;
         retirement.bump = *ctx.bumps.get("credit_retirement").unwrap();
 
+        // Use towards an authorized purpose counts as first transfer when none has been recorded yet
+        if use_type.authorized_use().is_some() {
+            let project_registry_id = ctx.accounts.project_registry.key();
+            let article6 = ctx.accounts.project_registry.article6.as_mut().unwrap();
+            if article6.first_transferred_at.is_none() {
+                article6.first_transferred_at = Some(retirement.retired_at);
+                article6.first_transfer_record = Some(retirement.key());
+
+                emit!(FirstTransferRecorded {
+                    project_registry_id,
+                    record: retirement.key(),
+                    destination_country: None,
+                    recorded_at: retirement.retired_at,
+                });
+            }
+        }
+
         // Burn tokens to represent retirement
         let burn_ctx = CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -200,11 +910,90 @@ pub mod carbon_registry {
 
         token::burn(burn_ctx, quantity)?;
 
+        // Mint a non-transferable certificate to the holder as proof of retirement
+        let registry_seeds = &[
+            b"registry",
+            ctx.accounts.registry.registry_seed.as_ref(),
+            &[ctx.accounts.registry.bump],
+        ];
+        let signer_seeds = &[&registry_seeds[..]];
+
+        // The retirement details live on the certificate account; the URI only has to locate them
+        let certificate_uri = format!(
+            "{}/retirements/{}.json",
+            ctx.accounts.registry.base_uri,
+            retirement.key()
+        );
+
+        let mint_to_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.certificate_mint.to_account_info(),
+                to: ctx.accounts.certificate_token_account.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::mint_to(mint_to_ctx.with_signer(signer_seeds), 1)?;
+
+        let metadata_ctx = CpiContext::new(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.certificate_metadata.to_account_info(),
+                mint: ctx.accounts.certificate_mint.to_account_info(),
+                mint_authority: ctx.accounts.registry.to_account_info(),
+                payer: ctx.accounts.owner.to_account_info(),
+                update_authority: ctx.accounts.registry.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+        );
+        metadata::create_metadata_accounts_v3(
+            metadata_ctx.with_signer(signer_seeds),
+            DataV2 {
+                name: format!("{} tCO2e V{}", quantity, ctx.accounts.project_registry.vintage_year),
+                symbol: "CRC".to_string(),
+                uri: certificate_uri.clone(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            },
+            false,
+            false,
+            None,
+        )?;
+
+        // Freezing the holder's account makes the certificate soulbound
+        let freeze_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.certificate_token_account.to_account_info(),
+                mint: ctx.accounts.certificate_mint.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::freeze_account(freeze_ctx.with_signer(signer_seeds))?;
+
+        let certificate = &mut ctx.accounts.retirement_certificate;
+        certificate.retirement = retirement.key();
+        certificate.mint = ctx.accounts.certificate_mint.key();
+        certificate.holder = ctx.accounts.certificate_holder.key();
+        certificate.project_registry = ctx.accounts.project_registry.key();
+        certificate.project_id = ctx.accounts.project_registry.project_id.clone();
+        certificate.vintage_year = ctx.accounts.project_registry.vintage_year;
+        certificate.quantity = quantity;
+        certificate.beneficiary = retirement.beneficiary.clone();
+        certificate.retirement_reason = retirement.retirement_reason.clone();
+        certificate.metadata_uri = certificate_uri;
+        certificate.issued_at = retirement.retired_at;
+        certificate.bump = *ctx.bumps.get("retirement_certificate").unwrap();
+
         let project_registry = &mut ctx.accounts.project_registry;
         project_registry.total_retired += quantity;
 
         let registry = &mut ctx.accounts.registry;
         registry.total_credits_retired += quantity;
+        ctx.accounts.country_stats.total_retired += quantity;
 
         emit!(CreditsRetired {
             retirement_id: retirement.key(),
@@ -212,59 +1001,455 @@ pub mod carbon_registry {
             project_registry: project_registry.key(),
             quantity,
             reason: retirement_reason,
+            beneficiary_wallet,
+            use_type,
+        });
+
+        emit!(RetirementCertificateIssued {
+            certificate_id: certificate.key(),
+            retirement_id: retirement.key(),
+            mint: certificate.mint,
+            holder: certificate.holder,
+            quantity,
         });
 
         Ok(())
     }
 
-    pub fn create_batch(
-        ctx: Context<CreateBatch>,
-        batch_id: String,
-        vintage_start: i64,
-        vintage_end: i64,
-        monitoring_report_uri: String,
+    pub fn initialize_buffer_pool(
+        ctx: Context<InitializeBufferPool>,
+        project_type: ProjectType,
+        buffer_percentage: u16,
     ) -> Result<()> {
-        require!(batch_id.len() <= 32, ErrorCode::BatchIdTooLong);
-        require!(vintage_end >= vintage_start, ErrorCode::InvalidVintagePeriod);
-        require!(monitoring_report_uri.len() <= 200, ErrorCode::MonitoringReportUriTooLong);
-
-        let batch = &mut ctx.accounts.credit_batch;
-        batch.batch_id = batch_id.clone();
-        batch.project_registry = ctx.accounts.project_registry.key();
-        batch.vintage_start = vintage_start;
-        batch.vintage_end = vintage_end;
-        batch.monitoring_report_uri = monitoring_report_uri;
-        batch.total_credits = 0;
-        batch.available_credits = 0;
-        batch.status = BatchStatus::Pending;
-        batch.created_at = Clock::get()?.unix_timestamp;
-        batch.bump = *ctx.bumps.get("credit_batch").unwrap();
-
-        emit!(BatchCreated {
-            batch_id: batch.key(),
-            project_registry: batch.project_registry,
-            batch_identifier: batch_id,
-            vintage_start,
-            vintage_end,
+        require!(buffer_percentage <= 10000, ErrorCode::InvalidBufferPercentage);
+
+        let buffer_pool = &mut ctx.accounts.buffer_pool;
+        buffer_pool.registry = ctx.accounts.registry.key();
+        buffer_pool.project_type = project_type.clone();
+        buffer_pool.buffer_percentage = buffer_percentage;
+        buffer_pool.total_contributed = 0;
+        buffer_pool.total_cancelled = 0;
+        buffer_pool.created_at = Clock::get()?.unix_timestamp;
+        buffer_pool.bump = *ctx.bumps.get("buffer_pool").unwrap();
+
+        emit!(BufferPoolConfigured {
+            buffer_pool_id: buffer_pool.key(),
+            project_type,
+            buffer_percentage,
         });
 
         Ok(())
     }
 
-    pub fn update_project_status(
-        ctx: Context<UpdateProjectStatus>,
-        new_status: ProjectRegistryStatus,
-        reason: String,
+    pub fn update_buffer_pool(
+        ctx: Context<UpdateBufferPool>,
+        buffer_percentage: u16,
     ) -> Result<()> {
-        require!(reason.len() <= 200, ErrorCode::ReasonTooLong);
+        require!(buffer_percentage <= 10000, ErrorCode::InvalidBufferPercentage);
 
-        let project_registry = &mut ctx.accounts.project_registry;
-        let old_status = project_registry.status;
-        project_registry.status = new_status;
+        let buffer_pool = &mut ctx.accounts.buffer_pool;
+        buffer_pool.buffer_percentage = buffer_percentage;
 
-        emit!(ProjectStatusUpdated {
-            project_registry_id: project_registry.key(),
-            old_status,
+        emit!(BufferPoolConfigured {
+            buffer_pool_id: buffer_pool.key(),
+            project_type: buffer_pool.project_type.clone(),
+            buffer_percentage,
+        });
+
+        Ok(())
+    }
+
+    pub fn initialize_buffer_vault(ctx: Context<InitializeBufferVault>) -> Result<()> {
+        emit!(BufferVaultInitialized {
+            buffer_vault: ctx.accounts.buffer_vault.key(),
+            project_registry: ctx.accounts.project_registry.key(),
+            credit_mint: ctx.accounts.credit_mint.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn report_reversal(
+        ctx: Context<ReportReversal>,
+        quantity: u64,
+        reason: String,
+    ) -> Result<()> {
+        require!(quantity > 0, ErrorCode::InvalidQuantity);
+        require!(reason.len() <= 200, ErrorCode::ReasonTooLong);
+        require!(ctx.accounts.buffer_vault.amount >= quantity, ErrorCode::InsufficientBufferCredits);
+        require!(
+            quantity <= ctx.accounts.project_registry.total_issued,
+            ErrorCode::ReversalExceedsIssuance
+        );
+
+        let registry_seeds = &[
+            b"registry",
+            ctx.accounts.registry.registry_seed.as_ref(),
+            &[ctx.accounts.registry.bump],
+        ];
+        let signer_seeds = &[&registry_seeds[..]];
+
+        // Cancel pooled buffer credits to cover the lost tonnes
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.credit_mint.to_account_info(),
+                from: ctx.accounts.buffer_vault.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::burn(burn_ctx.with_signer(signer_seeds), quantity)?;
+
+        let buffer_pool = &mut ctx.accounts.buffer_pool;
+        buffer_pool.total_cancelled += quantity;
+
+        // The reversed tonnes no longer count as issued for the project, its country or the registry
+        let project_registry = &mut ctx.accounts.project_registry;
+        project_registry.total_reversed += quantity;
+        project_registry.total_issued = project_registry
+            .total_issued
+            .checked_sub(quantity)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let registry = &mut ctx.accounts.registry;
+        registry.total_credits_issued = registry
+            .total_credits_issued
+            .checked_sub(quantity)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let country_stats = &mut ctx.accounts.country_stats;
+        country_stats.total_issued = country_stats
+            .total_issued
+            .checked_sub(quantity)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(ReversalReported {
+            project_registry: project_registry.key(),
+            source_project_registry: ctx.accounts.source_project_registry.key(),
+            buffer_pool: buffer_pool.key(),
+            quantity,
+            reason,
+            reported_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_retirement(
+        ctx: Context<ClaimRetirement>,
+        reporting_year: u16,
+    ) -> Result<()> {
+        require!((2000..=2100).contains(&reporting_year), ErrorCode::InvalidReportingYear);
+
+        let retirement = &ctx.accounts.credit_retirement;
+        require!(
+            retirement.attribution_holder() == ctx.accounts.claimant.key(),
+            ErrorCode::NotAttributionHolder
+        );
+
+        let claim = &mut ctx.accounts.retirement_claim;
+        claim.retirement = retirement.key();
+        claim.project_registry = retirement.project_registry;
+        claim.claimant = ctx.accounts.claimant.key();
+        claim.reporting_year = reporting_year;
+        claim.quantity = retirement.quantity;
+        claim.claimed_at = Clock::get()?.unix_timestamp;
+        claim.bump = *ctx.bumps.get("retirement_claim").unwrap();
+
+        emit!(RetirementClaimed {
+            claim_id: claim.key(),
+            retirement_id: claim.retirement,
+            claimant: claim.claimant,
+            reporting_year,
+            quantity: claim.quantity,
+        });
+
+        Ok(())
+    }
+
+    pub fn transfer_attribution(
+        ctx: Context<TransferAttribution>,
+        new_beneficiary: Pubkey,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.retirement_claim.data_is_empty(),
+            ErrorCode::AttributionAlreadyClaimed
+        );
+
+        let retirement = &mut ctx.accounts.credit_retirement;
+        require!(
+            retirement.attribution_holder() == ctx.accounts.holder.key(),
+            ErrorCode::NotAttributionHolder
+        );
+        require!(!retirement.attribution_transferred, ErrorCode::AttributionAlreadyTransferred);
+        require!(new_beneficiary != ctx.accounts.holder.key(), ErrorCode::InvalidBeneficiary);
+
+        retirement.beneficiary_wallet = Some(new_beneficiary);
+        retirement.attribution_transferred = true;
+
+        // The soulbound certificate follows the attribution: burn the old holder's token and
+        // mint the same certificate to the new beneficiary
+        let registry_seeds = &[
+            b"registry",
+            ctx.accounts.registry.registry_seed.as_ref(),
+            &[ctx.accounts.registry.bump],
+        ];
+        let signer_seeds = &[&registry_seeds[..]];
+
+        let thaw_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.holder_certificate_account.to_account_info(),
+                mint: ctx.accounts.certificate_mint.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::thaw_account(thaw_ctx.with_signer(signer_seeds))?;
+
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.certificate_mint.to_account_info(),
+                from: ctx.accounts.holder_certificate_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        );
+        token::burn(burn_ctx, 1)?;
+
+        let close_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.holder_certificate_account.to_account_info(),
+                destination: ctx.accounts.holder.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        );
+        token::close_account(close_ctx)?;
+
+        let mint_to_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.certificate_mint.to_account_info(),
+                to: ctx.accounts.beneficiary_certificate_account.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::mint_to(mint_to_ctx.with_signer(signer_seeds), 1)?;
+
+        let freeze_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.beneficiary_certificate_account.to_account_info(),
+                mint: ctx.accounts.certificate_mint.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::freeze_account(freeze_ctx.with_signer(signer_seeds))?;
+
+        let certificate = &mut ctx.accounts.retirement_certificate;
+        certificate.holder = new_beneficiary;
+
+        emit!(AttributionTransferred {
+            retirement_id: retirement.key(),
+            from_beneficiary: ctx.accounts.holder.key(),
+            to_beneficiary: new_beneficiary,
+            transferred_at: Clock::get()?.unix_timestamp,
+        });
+
+        emit!(RetirementCertificateIssued {
+            certificate_id: certificate.key(),
+            retirement_id: retirement.key(),
+            mint: certificate.mint,
+            holder: certificate.holder,
+            quantity: certificate.quantity,
+        });
+
+        Ok(())
+    }
+
+    pub fn create_batch(
+        ctx: Context<CreateBatch>,
+        batch_id: String,
+        vintage_start: i64,
+        vintage_end: i64,
+        monitoring_report_uri: String,
+    ) -> Result<()> {
+        require!(batch_id.len() <= 32, ErrorCode::BatchIdTooLong);
+        require!(vintage_end >= vintage_start, ErrorCode::InvalidVintagePeriod);
+        require!(monitoring_report_uri.len() <= 200, ErrorCode::MonitoringReportUriTooLong);
+        require!(
+            ctx.accounts.project_registry.covers_vintage(vintage_start, vintage_end),
+            ErrorCode::VintageOutsideCreditingPeriod
+        );
+
+        let batch = &mut ctx.accounts.credit_batch;
+        batch.batch_id = batch_id.clone();
+        batch.project_registry = ctx.accounts.project_registry.key();
+        batch.vintage_start = vintage_start;
+        batch.vintage_end = vintage_end;
+        batch.monitoring_report_uri = monitoring_report_uri;
+        batch.total_credits = 0;
+        batch.available_credits = 0;
+        batch.status = BatchStatus::Pending;
+        batch.created_at = Clock::get()?.unix_timestamp;
+        batch.bump = *ctx.bumps.get("credit_batch").unwrap();
+
+        emit!(BatchCreated {
+            batch_id: batch.key(),
+            project_registry: batch.project_registry,
+            batch_identifier: batch_id,
+            vintage_start,
+            vintage_end,
+        });
+
+        Ok(())
+    }
+
+    pub fn renew_crediting_period(
+        ctx: Context<RenewCreditingPeriod>,
+        crediting_period_years: u8,
+    ) -> Result<()> {
+        let methodology = &ctx.accounts.methodology;
+        let project_registry = &mut ctx.accounts.project_registry;
+        let verification = &ctx.accounts.verification_result;
+
+        require!(
+            project_registry.crediting_period_renewals < methodology.max_crediting_period_renewals,
+            ErrorCode::CreditingPeriodRenewalsExhausted
+        );
+        require!(
+            methodology.allows_crediting_period(crediting_period_years),
+            ErrorCode::InvalidCreditingPeriod
+        );
+        require!(!verification.under_challenge, ErrorCode::VerificationUnderChallenge);
+        // The verification must have been conducted within the final year of the current period
+        let final_year_start = project_registry
+            .crediting_period_end
+            .checked_sub(SECONDS_PER_YEAR)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(
+            verification.is_valid && verification.verified_at >= final_year_start,
+            ErrorCode::StaleVerification
+        );
+        // The verifier must still be accredited, for this sector and for the verified quantity
+        let verifier = &ctx.accounts.verifier;
+        verifier
+            .require_accredited(&ctx.accounts.accreditation_body)
+            .map_err(|_| error!(ErrorCode::VerifierNotAccredited))?;
+        verifier
+            .require_in_scope(
+                &ctx.accounts.verification_config,
+                &project_registry.project_type.verification_sector(),
+                verification.verified_credits,
+            )
+            .map_err(|_| error!(ErrorCode::VerifierOutOfScope))?;
+
+        project_registry.crediting_period_start = project_registry.crediting_period_end;
+        project_registry.crediting_period_end =
+            crediting_period_end(project_registry.crediting_period_start, crediting_period_years)?;
+        project_registry.crediting_period_renewals += 1;
+
+        emit!(CreditingPeriodRenewed {
+            project_registry_id: project_registry.key(),
+            verification_result: verification.key(),
+            crediting_period_start: project_registry.crediting_period_start,
+            crediting_period_end: project_registry.crediting_period_end,
+            renewals: project_registry.crediting_period_renewals,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_transfer_approval_required(
+        ctx: Context<SetTransferApprovalRequired>,
+        transfer_approval_required: bool,
+    ) -> Result<()> {
+        ctx.accounts.registry.transfer_approval_required = transfer_approval_required;
+        Ok(())
+    }
+
+    pub fn propose_project_transfer(
+        ctx: Context<ProposeProjectTransfer>,
+        new_developer: Pubkey,
+    ) -> Result<()> {
+        let project_registry = &mut ctx.accounts.project_registry;
+        project_registry.pending_developer = Some(new_developer);
+        project_registry.pending_transfer_approved = false;
+
+        emit!(ProjectTransferProposed {
+            project_registry_id: project_registry.key(),
+            current_developer: project_registry.project_developer,
+            pending_developer: new_developer,
+        });
+
+        Ok(())
+    }
+
+    pub fn approve_project_transfer(ctx: Context<ApproveProjectTransfer>) -> Result<()> {
+        let project_registry = &mut ctx.accounts.project_registry;
+        require!(project_registry.pending_developer.is_some(), ErrorCode::NoPendingProjectTransfer);
+        project_registry.pending_transfer_approved = true;
+
+        emit!(ProjectTransferApproved {
+            project_registry_id: project_registry.key(),
+            pending_developer: project_registry.pending_developer.unwrap(),
+            approved_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_project_transfer(ctx: Context<AcceptProjectTransfer>) -> Result<()> {
+        let project_registry = &mut ctx.accounts.project_registry;
+        require!(
+            !ctx.accounts.registry.transfer_approval_required || project_registry.pending_transfer_approved,
+            ErrorCode::ProjectTransferNotApproved
+        );
+
+        // Issuances, transfers and retirements reference the project account, so history carries over
+        let old_developer = project_registry.project_developer;
+        project_registry.project_developer = ctx.accounts.new_developer.key();
+        project_registry.pending_developer = None;
+        project_registry.pending_transfer_approved = false;
+
+        emit!(ProjectOwnershipTransferred {
+            project_registry_id: project_registry.key(),
+            old_developer,
+            new_developer: project_registry.project_developer,
+            transferred_at: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_project_status(
+        ctx: Context<UpdateProjectStatus>,
+        new_status: ProjectRegistryStatus,
+        reason: String,
+    ) -> Result<()> {
+        require!(reason.len() <= 200, ErrorCode::ReasonTooLong);
+
+        let project_registry = &mut ctx.accounts.project_registry;
+        let old_status = project_registry.status.clone();
+        require!(
+            old_status.can_transition_to(&new_status),
+            ErrorCode::InvalidStatusTransition
+        );
+        project_registry.status = new_status.clone();
+
+        let status_history = &mut ctx.accounts.status_history;
+        status_history.project_registry = project_registry.key();
+        status_history.sequence = project_registry.status_change_count;
+        status_history.old_status = old_status.clone();
+        status_history.new_status = new_status.clone();
+        status_history.reason = reason.clone();
+        status_history.actor = ctx.accounts.authority.key();
+        status_history.changed_at = Clock::get()?.unix_timestamp;
+        status_history.bump = *ctx.bumps.get("status_history").unwrap();
+        project_registry.status_change_count += 1;
+
+        emit!(ProjectStatusUpdated {
+            project_registry_id: project_registry.key(),
+            old_status,
             new_status,
             reason,
             updated_by: ctx.accounts.authority.key(),
@@ -273,6 +1458,73 @@ pub mod carbon_registry {
         Ok(())
     }
 
+    pub fn freeze_credit_account(ctx: Context<FreezeCreditAccount>) -> Result<()> {
+        let status = &ctx.accounts.project_registry.status;
+        require!(
+            *status == ProjectRegistryStatus::Suspended || *status == ProjectRegistryStatus::Terminated,
+            ErrorCode::ProjectNotSuspended
+        );
+
+        let registry_seeds = &[
+            b"registry",
+            ctx.accounts.registry.registry_seed.as_ref(),
+            &[ctx.accounts.registry.bump],
+        ];
+        let signer_seeds = &[&registry_seeds[..]];
+
+        let freeze_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.holder_token_account.to_account_info(),
+                mint: ctx.accounts.credit_mint.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::freeze_account(freeze_ctx.with_signer(signer_seeds))?;
+
+        emit!(CreditAccountFrozen {
+            project_registry: ctx.accounts.project_registry.key(),
+            token_account: ctx.accounts.holder_token_account.key(),
+            holder: ctx.accounts.holder_token_account.owner,
+            frozen_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn thaw_credit_account(ctx: Context<ThawCreditAccount>) -> Result<()> {
+        require!(
+            ctx.accounts.project_registry.status == ProjectRegistryStatus::Active,
+            ErrorCode::ProjectNotActive
+        );
+
+        let registry_seeds = &[
+            b"registry",
+            ctx.accounts.registry.registry_seed.as_ref(),
+            &[ctx.accounts.registry.bump],
+        ];
+        let signer_seeds = &[&registry_seeds[..]];
+
+        let thaw_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.holder_token_account.to_account_info(),
+                mint: ctx.accounts.credit_mint.to_account_info(),
+                authority: ctx.accounts.registry.to_account_info(),
+            },
+        );
+        token::thaw_account(thaw_ctx.with_signer(signer_seeds))?;
+
+        emit!(CreditAccountThawed {
+            project_registry: ctx.accounts.project_registry.key(),
+            token_account: ctx.accounts.holder_token_account.key(),
+            holder: ctx.accounts.holder_token_account.owner,
+            thawed_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
     pub fn add_project_metadata(
         ctx: Context<AddProjectMetadata>,
         metadata_type: MetadataType,
@@ -284,7 +1536,7 @@ pub mod carbon_registry {
 
         let metadata = &mut ctx.accounts.project_metadata;
         metadata.project_registry = ctx.accounts.project_registry.key();
-        metadata.metadata_type = metadata_type;
+        metadata.metadata_type = metadata_type.clone();
         metadata.metadata_uri = metadata_uri;
         metadata.description = description;
         metadata.added_at = Clock::get()?.unix_timestamp;
@@ -301,41 +1553,344 @@ pub mod carbon_registry {
 }
 
 #[derive(Accounts)]
+#[instruction(registry_seed: Pubkey)]
 pub struct InitializeRegistry<'info> {
     #[account(
         init,
-        payer = authority,
-        space = 8 + Registry::INIT_SPACE,
-        seeds = [b"registry", authority.key().as_ref()],
+        payer = authority,
+        space = 8 + Registry::INIT_SPACE,
+        seeds = [b"registry", registry_seed.as_ref()],
+        bump
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeRegistryAuthority<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAuthority)]
+    pub registry: Account<'info, Registry>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptRegistryAuthority<'info> {
+    #[account(
+        mut,
+        constraint = registry.pending_authority == Some(new_authority.key()) @ ErrorCode::NotPendingAuthority
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        init,
+        payer = new_authority,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [b"role", registry.key().as_ref(), new_authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump
+    )]
+    pub admin_role: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), new_authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = existing_admin_role.bump
+    )]
+    pub existing_admin_role: Option<Account<'info, RoleAssignment>>,
+    /// CHECK: The outgoing authority's admin role; closed if it still exists
+    #[account(
+        mut,
+        seeds = [b"role", registry.key().as_ref(), registry.authority.as_ref(), &[Role::Admin.seed()]],
+        bump
+    )]
+    pub old_admin_role: UncheckedAccount<'info>,
+    /// CHECK: The outgoing authority, refunded the closed role's rent
+    #[account(mut, address = registry.authority @ ErrorCode::UnauthorizedAuthority)]
+    pub old_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub new_authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey, role: Role)]
+pub struct GrantRole<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [b"role", registry.key().as_ref(), holder.as_ref(), &[role.seed()]],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), admin.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        close = admin,
+        has_one = registry @ ErrorCode::RoleRegistryMismatch
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), admin.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(methodology_id: String, version: String)]
+pub struct AddMethodology<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Methodology::INIT_SPACE,
+        seeds = [b"methodology", registry.key().as_ref(), methodology_id.as_bytes(), version.as_bytes()],
+        bump
+    )]
+    pub methodology: Account<'info, Methodology>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMethodologyStatus<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::MethodologyRegistryMismatch)]
+    pub methodology: Account<'info, Methodology>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(country_code: [u8; 2])]
+pub struct InitializeCountryStats<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CountryStats::INIT_SPACE,
+        seeds = [b"country_stats", registry.key().as_ref(), country_code.as_ref()],
+        bump
+    )]
+    pub country_stats: Account<'info, CountryStats>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetDesignatedNationalAuthority<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::CountryStatsRegistryMismatch)]
+    pub country_stats: Account<'info, CountryStats>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AuthorizeArticle6<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    #[account(
+        seeds = [b"country_stats", registry.key().as_ref(), project_registry.country_code.as_ref()],
+        bump = country_stats.bump,
+        constraint = country_stats.designated_national_authority == Some(authority.key()) @ ErrorCode::UnauthorizedNationalAuthority
+    )]
+    pub country_stats: Account<'info, CountryStats>,
+    pub registry: Account<'info, Registry>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RecordCorrespondingAdjustment<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    #[account(
+        seeds = [b"country_stats", registry.key().as_ref(), project_registry.country_code.as_ref()],
+        bump = country_stats.bump,
+        constraint = country_stats.designated_national_authority == Some(authority.key()) @ ErrorCode::UnauthorizedNationalAuthority
+    )]
+    pub country_stats: Account<'info, CountryStats>,
+    pub registry: Account<'info, Registry>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetIssuanceLevies<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetApplicationDeposit<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawForfeitedDeposits<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    /// CHECK: Any wallet the admin sends the forfeited deposits to, e.g. the registry treasury
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_id: String)]
+pub struct SubmitProjectApplication<'info> {
+    #[account(
+        init,
+        payer = developer,
+        space = 8 + ProjectApplication::INIT_SPACE,
+        seeds = [b"project_application", registry.key().as_ref(), project_id.as_bytes()],
         bump
     )]
+    pub project_application: Account<'info, ProjectApplication>,
+    #[account(has_one = registry @ ErrorCode::MethodologyRegistryMismatch)]
+    pub methodology: Account<'info, Methodology>,
     pub registry: Account<'info, Registry>,
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub developer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(project_id: String)]
-pub struct RegisterProject<'info> {
+pub struct ApproveProjectApplication<'info> {
+    #[account(
+        mut,
+        close = developer,
+        has_one = registry @ ErrorCode::ProjectRegistryMismatch,
+        has_one = developer @ ErrorCode::ApplicationDeveloperMismatch,
+        has_one = methodology @ ErrorCode::MethodologyMismatch
+    )]
+    pub project_application: Account<'info, ProjectApplication>,
+    pub methodology: Account<'info, Methodology>,
     #[account(
         init,
         payer = authority,
         space = 8 + ProjectRegistry::INIT_SPACE,
-        seeds = [b"project_registry", registry.key().as_ref(), project_id.as_bytes()],
+        seeds = [b"project_registry", registry.key().as_ref(), project_application.project_id.as_bytes()],
         bump
     )]
     pub project_registry: Account<'info, ProjectRegistry>,
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = registry,
+        mint::freeze_authority = registry,
+        seeds = [b"credit_mint", project_registry.key().as_ref()],
+        bump
+    )]
+    pub credit_mint: Account<'info, Mint>,
     #[account(mut)]
     pub registry: Account<'info, Registry>,
-    #[account(mut, constraint = registry.authority == authority.key())]
+    #[account(
+        mut,
+        seeds = [b"country_stats", registry.key().as_ref(), project_application.country_code.as_ref()],
+        bump = country_stats.bump
+    )]
+    pub country_stats: Account<'info, CountryStats>,
+    /// CHECK: The application developer, refunded on approval
+    #[account(mut)]
+    pub developer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::ProjectApprover.seed()]],
+        bump = approver_role.bump
+    )]
+    pub approver_role: Account<'info, RoleAssignment>,
+    #[account(mut)]
     pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct IssueCredits<'info> {
+pub struct RejectProjectApplication<'info> {
+    #[account(
+        mut,
+        close = developer,
+        has_one = registry @ ErrorCode::ProjectRegistryMismatch,
+        has_one = developer @ ErrorCode::ApplicationDeveloperMismatch
+    )]
+    pub project_application: Account<'info, ProjectApplication>,
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+    /// CHECK: The application developer, refunded the application rent
     #[account(mut)]
+    pub developer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::ProjectApprover.seed()]],
+        bump = approver_role.bump
+    )]
+    pub approver_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct IssueCredits<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
     pub project_registry: Account<'info, ProjectRegistry>,
     #[account(
         init,
@@ -347,20 +1902,93 @@ pub struct IssueCredits<'info> {
     pub credit_issuance: Account<'info, CreditIssuance>,
     #[account(mut)]
     pub registry: Account<'info, Registry>,
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"country_stats", registry.key().as_ref(), project_registry.country_code.as_ref()],
+        bump = country_stats.bump
+    )]
+    pub country_stats: Account<'info, CountryStats>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Issuer.seed()]],
+        bump = issuer_role.bump
+    )]
+    pub issuer_role: Account<'info, RoleAssignment>,
+    #[account(mut, address = project_registry.credit_mint @ ErrorCode::CreditMintMismatch)]
     pub credit_mint: Account<'info, Mint>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == credit_mint.key() @ ErrorCode::TokenAccountMintMismatch,
+        constraint = recipient_token_account.owner == recipient.key() @ ErrorCode::TokenAccountOwnerMismatch
+    )]
     pub recipient_token_account: Account<'info, TokenAccount>,
     /// CHECK: This is the recipient of the credits
     pub recipient: UncheckedAccount<'info>,
-    #[account(mut, constraint = registry.authority == authority.key())]
+    #[account(
+        mut,
+        seeds = [b"buffer_pool", registry.key().as_ref(), &[project_registry.project_type.seed()]],
+        bump
+    )]
+    pub buffer_pool: Option<Account<'info, BufferPool>>,
+    #[account(
+        mut,
+        seeds = [b"buffer_vault", project_registry.key().as_ref()],
+        bump,
+        token::mint = credit_mint,
+        token::authority = registry
+    )]
+    pub buffer_vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = adaptation_fund_token_account.mint == credit_mint.key() @ ErrorCode::TokenAccountMintMismatch,
+        constraint = Some(adaptation_fund_token_account.owner) == registry.adaptation_fund @ ErrorCode::AdaptationFundMismatch
+    )]
+    pub adaptation_fund_token_account: Option<Account<'info, TokenAccount>>,
+    #[account(mut)]
     pub authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct CancelIssuance<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    #[account(
+        mut,
+        constraint = credit_issuance.project_registry == project_registry.key() @ ErrorCode::IssuanceProjectMismatch
+    )]
+    pub credit_issuance: Account<'info, CreditIssuance>,
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        seeds = [b"country_stats", registry.key().as_ref(), project_registry.country_code.as_ref()],
+        bump = country_stats.bump
+    )]
+    pub country_stats: Account<'info, CountryStats>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Issuer.seed()]],
+        bump = issuer_role.bump
+    )]
+    pub issuer_role: Account<'info, RoleAssignment>,
+    #[account(mut, address = project_registry.credit_mint @ ErrorCode::CreditMintMismatch)]
+    pub credit_mint: Account<'info, Mint>,
+    // Buffer credits leave the pool only through report_reversal, which keeps the pool's accounting
+    #[account(
+        mut,
+        constraint = source_token_account.mint == credit_mint.key() @ ErrorCode::TokenAccountMintMismatch,
+        constraint = source_token_account.key()
+            != Pubkey::find_program_address(&[b"buffer_vault", project_registry.key().as_ref()], &crate::ID).0
+            @ ErrorCode::BufferCreditsNotCancellable
+    )]
+    pub source_token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
 #[derive(Accounts)]
 pub struct TransferCredits<'info> {
+    #[account(mut)]
     pub project_registry: Account<'info, ProjectRegistry>,
     #[account(
         init,
@@ -370,9 +1998,17 @@ pub struct TransferCredits<'info> {
         bump
     )]
     pub transfer_record: Account<'info, TransferRecord>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = from_token_account.mint == project_registry.credit_mint @ ErrorCode::TokenAccountMintMismatch,
+        constraint = from_token_account.owner == from_owner.key() @ ErrorCode::TokenAccountOwnerMismatch
+    )]
     pub from_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = to_token_account.mint == project_registry.credit_mint @ ErrorCode::TokenAccountMintMismatch,
+        constraint = to_token_account.owner == to_owner.key() @ ErrorCode::TokenAccountOwnerMismatch
+    )]
     pub to_token_account: Account<'info, TokenAccount>,
     #[account(mut)]
     pub from_owner: Signer<'info>,
@@ -384,7 +2020,7 @@ pub struct TransferCredits<'info> {
 
 #[derive(Accounts)]
 pub struct RetireCredits<'info> {
-    #[account(mut)]
+    #[account(mut, has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
     pub project_registry: Account<'info, ProjectRegistry>,
     #[account(
         init,
@@ -396,19 +2032,233 @@ pub struct RetireCredits<'info> {
     pub credit_retirement: Account<'info, CreditRetirement>,
     #[account(mut)]
     pub registry: Account<'info, Registry>,
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"country_stats", registry.key().as_ref(), project_registry.country_code.as_ref()],
+        bump = country_stats.bump
+    )]
+    pub country_stats: Account<'info, CountryStats>,
+    #[account(mut, address = project_registry.credit_mint @ ErrorCode::CreditMintMismatch)]
     pub credit_mint: Account<'info, Mint>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == credit_mint.key() @ ErrorCode::TokenAccountMintMismatch,
+        constraint = owner_token_account.owner == owner.key() @ ErrorCode::TokenAccountOwnerMismatch
+    )]
     pub owner_token_account: Account<'info, TokenAccount>,
     #[account(mut)]
     pub owner: Signer<'info>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + RetirementCertificate::INIT_SPACE,
+        seeds = [b"retirement_certificate", credit_retirement.key().as_ref()],
+        bump
+    )]
+    pub retirement_certificate: Account<'info, RetirementCertificate>,
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = registry,
+        mint::freeze_authority = registry,
+        seeds = [b"certificate_mint", credit_retirement.key().as_ref()],
+        bump
+    )]
+    pub certificate_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = certificate_mint,
+        associated_token::authority = certificate_holder
+    )]
+    pub certificate_token_account: Account<'info, TokenAccount>,
+    /// CHECK: The retiring owner or the beneficiary wallet receiving the certificate
+    pub certificate_holder: UncheckedAccount<'info>,
+    /// CHECK: Initialized and validated by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), certificate_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub certificate_metadata: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(project_type: ProjectType)]
+pub struct InitializeBufferPool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BufferPool::INIT_SPACE,
+        seeds = [b"buffer_pool", registry.key().as_ref(), &[project_type.seed()]],
+        bump
+    )]
+    pub buffer_pool: Account<'info, BufferPool>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateBufferPool<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::BufferPoolMismatch)]
+    pub buffer_pool: Account<'info, BufferPool>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeBufferVault<'info> {
+    #[account(has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"buffer_vault", project_registry.key().as_ref()],
+        bump,
+        token::mint = credit_mint,
+        token::authority = registry
+    )]
+    pub buffer_vault: Account<'info, TokenAccount>,
+    #[account(address = project_registry.credit_mint @ ErrorCode::CreditMintMismatch)]
+    pub credit_mint: Account<'info, Mint>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Issuer.seed()]],
+        bump = issuer_role.bump
+    )]
+    pub issuer_role: Account<'info, RoleAssignment>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ReportReversal<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    #[account(
+        mut,
+        seeds = [b"buffer_pool", registry.key().as_ref(), &[project_registry.project_type.seed()]],
+        bump = buffer_pool.bump
+    )]
+    pub buffer_pool: Account<'info, BufferPool>,
+    #[account(
+        constraint = source_project_registry.registry == registry.key() @ ErrorCode::ProjectRegistryMismatch,
+        constraint = source_project_registry.project_type == project_registry.project_type @ ErrorCode::BufferPoolMismatch
+    )]
+    pub source_project_registry: Account<'info, ProjectRegistry>,
+    #[account(
+        mut,
+        seeds = [b"buffer_vault", source_project_registry.key().as_ref()],
+        bump,
+        constraint = buffer_vault.mint == credit_mint.key() @ ErrorCode::TokenAccountMintMismatch
+    )]
+    pub buffer_vault: Account<'info, TokenAccount>,
+    #[account(mut, address = source_project_registry.credit_mint @ ErrorCode::CreditMintMismatch)]
+    pub credit_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        seeds = [b"country_stats", registry.key().as_ref(), project_registry.country_code.as_ref()],
+        bump = country_stats.bump
+    )]
+    pub country_stats: Account<'info, CountryStats>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Auditor.seed()]],
+        bump = auditor_role.bump
+    )]
+    pub auditor_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRetirement<'info> {
+    pub credit_retirement: Account<'info, CreditRetirement>,
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + RetirementClaim::INIT_SPACE,
+        seeds = [b"retirement_claim", credit_retirement.key().as_ref()],
+        bump
+    )]
+    pub retirement_claim: Account<'info, RetirementClaim>,
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_beneficiary: Pubkey)]
+pub struct TransferAttribution<'info> {
+    #[account(mut, has_one = project_registry @ ErrorCode::RetirementProjectMismatch)]
+    pub credit_retirement: Account<'info, CreditRetirement>,
+    /// CHECK: Must still be uninitialized, i.e. the attribution has not been claimed
+    #[account(
+        seeds = [b"retirement_claim", credit_retirement.key().as_ref()],
+        bump
+    )]
+    pub retirement_claim: UncheckedAccount<'info>,
+    #[account(has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        mut,
+        seeds = [b"retirement_certificate", credit_retirement.key().as_ref()],
+        bump = retirement_certificate.bump
+    )]
+    pub retirement_certificate: Account<'info, RetirementCertificate>,
+    #[account(mut, address = retirement_certificate.mint)]
+    pub certificate_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = certificate_mint,
+        associated_token::authority = holder
+    )]
+    pub holder_certificate_account: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = holder,
+        associated_token::mint = certificate_mint,
+        associated_token::authority = new_beneficiary_wallet
+    )]
+    pub beneficiary_certificate_account: Account<'info, TokenAccount>,
+    /// CHECK: The wallet receiving the attribution and the certificate
+    #[account(address = new_beneficiary)]
+    pub new_beneficiary_wallet: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub holder: Signer<'info>,
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 #[instruction(batch_id: String)]
 pub struct CreateBatch<'info> {
+    #[account(has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
     pub project_registry: Account<'info, ProjectRegistry>,
     #[account(
         init,
@@ -418,22 +2268,160 @@ pub struct CreateBatch<'info> {
         bump
     )]
     pub credit_batch: Account<'info, CreditBatch>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Issuer.seed()]],
+        bump = issuer_role.bump
+    )]
+    pub issuer_role: Account<'info, RoleAssignment>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct RenewCreditingPeriod<'info> {
+    #[account(
+        mut,
+        has_one = registry @ ErrorCode::ProjectRegistryMismatch,
+        has_one = methodology @ ErrorCode::MethodologyMismatch
+    )]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    pub methodology: Account<'info, Methodology>,
+    #[account(constraint = verification_result.project == project_registry.key() @ ErrorCode::VerificationProjectMismatch)]
+    pub verification_result: Account<'info, VerificationResult>,
+    #[account(address = verification_result.verifier @ ErrorCode::VerifierMismatch)]
+    pub verifier: Account<'info, Verifier>,
+    #[account(address = verifier.accreditation_body @ ErrorCode::VerifierNotAccredited)]
+    pub accreditation_body: Account<'info, AccreditationBody>,
+    #[account(
+        seeds = [b"verification_config"],
+        bump = verification_config.bump,
+        seeds::program = carbon_verification::ID
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::ProjectApprover.seed()]],
+        bump = approver_role.bump
+    )]
+    pub approver_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTransferApprovalRequired<'info> {
+    #[account(mut)]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeProjectTransfer<'info> {
+    #[account(
+        mut,
+        constraint = project_registry.project_developer == developer.key() @ ErrorCode::UnauthorizedProjectSigner
+    )]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    pub developer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProjectTransfer<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::ProjectApprover.seed()]],
+        bump = approver_role.bump
+    )]
+    pub approver_role: Account<'info, RoleAssignment>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptProjectTransfer<'info> {
+    #[account(
+        mut,
+        has_one = registry @ ErrorCode::ProjectRegistryMismatch,
+        constraint = project_registry.pending_developer == Some(new_developer.key()) @ ErrorCode::NotPendingDeveloper
+    )]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    pub registry: Account<'info, Registry>,
+    pub new_developer: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateProjectStatus<'info> {
+    #[account(mut, has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProjectStatusHistory::INIT_SPACE,
+        seeds = [
+            b"status_history",
+            project_registry.key().as_ref(),
+            &project_registry.status_change_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub status_history: Account<'info, ProjectStatusHistory>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::ProjectApprover.seed()]],
+        bump = approver_role.bump
+    )]
+    pub approver_role: Account<'info, RoleAssignment>,
     #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeCreditAccount<'info> {
+    #[account(has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
+    pub project_registry: Account<'info, ProjectRegistry>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    #[account(address = project_registry.credit_mint @ ErrorCode::CreditMintMismatch)]
+    pub credit_mint: Account<'info, Mint>,
+    #[account(mut, constraint = holder_token_account.mint == credit_mint.key() @ ErrorCode::TokenAccountMintMismatch)]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ThawCreditAccount<'info> {
+    #[account(has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
     pub project_registry: Account<'info, ProjectRegistry>,
-    #[account(constraint = registry.authority == authority.key())]
     pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::Admin.seed()]],
+        bump = admin_role.bump
+    )]
+    pub admin_role: Account<'info, RoleAssignment>,
+    #[account(address = project_registry.credit_mint @ ErrorCode::CreditMintMismatch)]
+    pub credit_mint: Account<'info, Mint>,
+    #[account(mut, constraint = holder_token_account.mint == credit_mint.key() @ ErrorCode::TokenAccountMintMismatch)]
+    pub holder_token_account: Account<'info, TokenAccount>,
     pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
 pub struct AddProjectMetadata<'info> {
+    #[account(has_one = registry @ ErrorCode::ProjectRegistryMismatch)]
     pub project_registry: Account<'info, ProjectRegistry>,
     #[account(
         init,
@@ -442,8 +2430,18 @@ pub struct AddProjectMetadata<'info> {
         seeds = [b"project_metadata", project_registry.key().as_ref(), authority.key().as_ref()],
         bump
     )]
-    pub project_metadata: Account<'info, ProjectMetadata>,
-    #[account(mut)]
+    pub project_metadata: Account<'info, ProjectMetadata>,
+    pub registry: Account<'info, Registry>,
+    #[account(
+        seeds = [b"role", registry.key().as_ref(), authority.key().as_ref(), &[Role::MetadataEditor.seed()]],
+        bump = metadata_editor_role.bump
+    )]
+    pub metadata_editor_role: Option<Account<'info, RoleAssignment>>,
+    #[account(
+        mut,
+        constraint = authority.key() == project_registry.project_developer
+            || metadata_editor_role.is_some() @ ErrorCode::UnauthorizedProjectSigner
+    )]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
@@ -452,36 +2450,137 @@ pub struct AddProjectMetadata<'info> {
 #[derive(InitSpace)]
 pub struct Registry {
     pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub registry_seed: Pubkey,
+    pub application_deposit: u64,
+    pub forfeited_deposits: u64,
+    pub transfer_approval_required: bool,
+    pub share_of_proceeds_bps: u16,
+    pub omge_bps: u16,
+    pub adaptation_fund: Option<Pubkey>,
     #[max_len(64)]
     pub registry_name: String,
     #[max_len(200)]
     pub base_uri: String,
     pub total_credits_issued: u64,
     pub total_credits_retired: u64,
+    pub total_share_of_proceeds: u64,
+    pub total_omge_cancelled: u64,
+    pub total_projects: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoleAssignment {
+    pub registry: Pubkey,
+    pub holder: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CountryStats {
+    pub registry: Pubkey,
+    pub country_code: [u8; 2],
     pub total_projects: u64,
+    pub total_issued: u64,
+    pub total_retired: u64,
+    pub designated_national_authority: Option<Pubkey>,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Methodology {
+    pub registry: Pubkey,
+    #[max_len(32)]
+    pub methodology_id: String,
+    #[max_len(16)]
+    pub version: String,
+    pub standard: CarbonStandard,
+    pub sector: ProjectType,
+    pub min_crediting_period_years: u8,
+    pub max_crediting_period_years: u8,
+    pub max_crediting_period_renewals: u8,
+    pub status: MethodologyStatus,
     pub created_at: i64,
     pub bump: u8,
 }
 
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectApplication {
+    pub registry: Pubkey,
+    pub developer: Pubkey,
+    #[max_len(32)]
+    pub project_id: String,
+    pub vintage_year: u16,
+    pub methodology: Pubkey,
+    pub country_code: [u8; 2],
+    pub subdivision_code: [u8; 3],
+    pub project_type: ProjectType,
+    #[max_len(5, 200)]
+    pub documentation_uris: Vec<String>,
+    pub crediting_period_start: i64,
+    pub crediting_period_years: u8,
+    pub deposit: u64,
+    pub submitted_at: i64,
+    pub bump: u8,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct ProjectRegistry {
     #[max_len(32)]
     pub project_id: String,
     pub vintage_year: u16,
-    #[max_len(100)]
-    pub methodology: String,
-    #[max_len(3)]
-    pub country_code: String,
+    pub methodology: Pubkey,
+    pub country_code: [u8; 2],
+    pub subdivision_code: [u8; 3],
     pub project_developer: Pubkey,
+    pub project_type: ProjectType,
     pub registry: Pubkey,
+    pub credit_mint: Pubkey,
     pub total_issued: u64,
     pub total_retired: u64,
+    pub total_reversed: u64,
+    pub pending_developer: Option<Pubkey>,
+    pub pending_transfer_approved: bool,
     pub status: ProjectRegistryStatus,
+    pub status_change_count: u64,
+    pub crediting_period_start: i64,
+    pub crediting_period_end: i64,
+    pub crediting_period_renewals: u8,
+    pub article6: Option<Article6Authorization>,
     pub registered_at: i64,
     pub bump: u8,
 }
 
+impl ProjectRegistry {
+    pub fn covers_vintage(&self, vintage_start: i64, vintage_end: i64) -> bool {
+        vintage_start >= self.crediting_period_start && vintage_end <= self.crediting_period_end
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectStatusHistory {
+    pub project_registry: Pubkey,
+    pub sequence: u64,
+    pub old_status: ProjectRegistryStatus,
+    pub new_status: ProjectRegistryStatus,
+    #[max_len(200)]
+    pub reason: String,
+    pub actor: Pubkey,
+    pub changed_at: i64,
+    pub bump: u8,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct CreditIssuance {
@@ -490,8 +2589,30 @@ pub struct CreditIssuance {
     pub serial_number_prefix: String,
     pub quantity: u64,
     pub issuance_date: i64,
+    pub vintage_start: i64,
+    pub vintage_end: i64,
     pub issued_to: Pubkey,
     pub status: IssuanceStatus,
+    pub buffer_contribution: u64,
+    pub share_of_proceeds: u64,
+    pub omge_cancelled: u64,
+    pub cancelled_quantity: u64,
+    #[max_len(200)]
+    pub cancellation_reason: Option<String>,
+    pub cancelled_at: Option<i64>,
+    pub article6_authorized: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BufferPool {
+    pub registry: Pubkey,
+    pub project_type: ProjectType,
+    pub buffer_percentage: u16,
+    pub total_contributed: u64,
+    pub total_cancelled: u64,
     pub created_at: i64,
     pub bump: u8,
 }
@@ -505,6 +2626,7 @@ pub struct TransferRecord {
     pub quantity: u64,
     #[max_len(200)]
     pub transfer_reason: String,
+    pub destination_country: Option<[u8; 2]>,
     pub transferred_at: i64,
     pub bump: u8,
 }
@@ -519,10 +2641,72 @@ pub struct CreditRetirement {
     pub retirement_reason: String,
     #[max_len(100)]
     pub beneficiary: String,
+    pub beneficiary_wallet: Option<Pubkey>,
+    pub attribution_transferred: bool,
+    pub use_type: RetirementUse,
     pub retired_at: i64,
     pub bump: u8,
 }
 
+impl CreditRetirement {
+    pub fn attribution_holder(&self) -> Pubkey {
+        self.beneficiary_wallet.unwrap_or(self.owner)
+    }
+}
+
+// End of a crediting period of `years` years starting at `start`
+fn crediting_period_end(start: i64, years: u8) -> Result<i64> {
+    (years as i64)
+        .checked_mul(SECONDS_PER_YEAR)
+        .and_then(|length| start.checked_add(length))
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
+
+// Gregorian calendar year (UTC) of a Unix timestamp
+fn calendar_year(timestamp: i64) -> i64 {
+    let days = timestamp.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Months are counted from March, so January and February belong to the next year
+    let month = (5 * day_of_year + 2) / 153;
+    era * 400 + year_of_era + if month >= 10 { 1 } else { 0 }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RetirementClaim {
+    pub retirement: Pubkey,
+    pub project_registry: Pubkey,
+    pub claimant: Pubkey,
+    pub reporting_year: u16,
+    pub quantity: u64,
+    pub claimed_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RetirementCertificate {
+    pub retirement: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub project_registry: Pubkey,
+    #[max_len(32)]
+    pub project_id: String,
+    pub vintage_year: u16,
+    pub quantity: u64,
+    #[max_len(100)]
+    pub beneficiary: String,
+    #[max_len(200)]
+    pub retirement_reason: String,
+    #[max_len(200)]
+    pub metadata_uri: String,
+    pub issued_at: i64,
+    pub bump: u8,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct CreditBatch {
@@ -553,6 +2737,21 @@ pub struct ProjectMetadata {
     pub bump: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    Admin,
+    Issuer,
+    ProjectApprover,
+    MetadataEditor,
+    Auditor,
+}
+
+impl Role {
+    pub fn seed(&self) -> u8 {
+        self.clone() as u8
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum ProjectRegistryStatus {
     Active,
@@ -561,6 +2760,118 @@ pub enum ProjectRegistryStatus {
     UnderReview,
 }
 
+impl Methodology {
+    pub fn allows_crediting_period(&self, years: u8) -> bool {
+        years >= self.min_crediting_period_years && years <= self.max_crediting_period_years
+    }
+}
+
+impl ProjectRegistryStatus {
+    pub fn can_transition_to(&self, next: &ProjectRegistryStatus) -> bool {
+        use ProjectRegistryStatus::*;
+        matches!(
+            (self, next),
+            (Active, UnderReview)
+                | (Active, Suspended)
+                | (Active, Terminated)
+                | (UnderReview, Active)
+                | (UnderReview, Suspended)
+                | (UnderReview, Terminated)
+                | (Suspended, Active)
+                | (Suspended, UnderReview)
+                | (Suspended, Terminated)
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProjectType {
+    Forestry,
+    RenewableEnergy,
+    EnergyEfficiency,
+    Methane,
+    Transportation,
+    Agriculture,
+    WasteManagement,
+    CarbonCapture,
+}
+
+impl ProjectType {
+    pub fn seed(&self) -> u8 {
+        self.clone() as u8
+    }
+
+    pub fn has_reversal_risk(&self) -> bool {
+        matches!(self, ProjectType::Forestry | ProjectType::Agriculture)
+    }
+
+    pub fn verification_sector(&self) -> carbon_verification::ProjectType {
+        match self {
+            ProjectType::Forestry => carbon_verification::ProjectType::Forestry,
+            ProjectType::RenewableEnergy => carbon_verification::ProjectType::RenewableEnergy,
+            ProjectType::EnergyEfficiency => carbon_verification::ProjectType::EnergyEfficiency,
+            ProjectType::Methane => carbon_verification::ProjectType::Methane,
+            ProjectType::Transportation => carbon_verification::ProjectType::Transportation,
+            ProjectType::Agriculture => carbon_verification::ProjectType::Agriculture,
+            ProjectType::WasteManagement => carbon_verification::ProjectType::WasteManagement,
+            ProjectType::CarbonCapture => carbon_verification::ProjectType::CarbonCapture,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct Article6Authorization {
+    #[max_len(3)]
+    pub authorized_uses: Vec<AuthorizedUse>,
+    pub authorized_by: Pubkey,
+    pub authorized_at: i64,
+    pub corresponding_adjustment_year: Option<u16>,
+    pub first_transferred_at: Option<i64>,
+    pub first_transfer_record: Option<Pubkey>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum AuthorizedUse {
+    Ndc,
+    Corsia,
+    OtherInternationalMitigation,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum RetirementUse {
+    Voluntary,
+    Ndc,
+    Corsia,
+    OtherInternationalMitigation,
+}
+
+impl RetirementUse {
+    pub fn authorized_use(&self) -> Option<AuthorizedUse> {
+        match self {
+            RetirementUse::Voluntary => None,
+            RetirementUse::Ndc => Some(AuthorizedUse::Ndc),
+            RetirementUse::Corsia => Some(AuthorizedUse::Corsia),
+            RetirementUse::OtherInternationalMitigation => Some(AuthorizedUse::OtherInternationalMitigation),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum CarbonStandard {
+    Verra,
+    GoldStandard,
+    Cdm,
+    Car,
+    Acr,
+    PlanVivo,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum MethodologyStatus {
+    Active,
+    Deprecated,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum IssuanceStatus {
     Active,
@@ -593,12 +2904,120 @@ pub struct RegistryInitialized {
     pub registry_name: String,
 }
 
+#[event]
+pub struct RegistryAuthorityProposed {
+    pub registry_id: Pubkey,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct RegistryAuthorityAccepted {
+    pub registry_id: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub registry: Pubkey,
+    pub holder: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub registry: Pubkey,
+    pub holder: Pubkey,
+    pub role: Role,
+    pub revoked_by: Pubkey,
+}
+
+#[event]
+pub struct Article6Authorized {
+    pub project_registry_id: Pubkey,
+    pub country_code: [u8; 2],
+    pub authorized_uses: Vec<AuthorizedUse>,
+    pub authorized_by: Pubkey,
+}
+
+#[event]
+pub struct CorrespondingAdjustmentRecorded {
+    pub project_registry_id: Pubkey,
+    pub reporting_year: u16,
+    pub recorded_by: Pubkey,
+}
+
+#[event]
+pub struct FirstTransferRecorded {
+    pub project_registry_id: Pubkey,
+    pub record: Pubkey,
+    pub destination_country: Option<[u8; 2]>,
+    pub recorded_at: i64,
+}
+
+#[event]
+pub struct MethodologyAdded {
+    pub methodology: Pubkey,
+    pub methodology_id: String,
+    pub version: String,
+}
+
+#[event]
+pub struct MethodologyStatusUpdated {
+    pub methodology: Pubkey,
+    pub status: MethodologyStatus,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct ProjectApplicationSubmitted {
+    pub application_id: Pubkey,
+    pub registry: Pubkey,
+    pub developer: Pubkey,
+    pub project_id: String,
+    pub deposit: u64,
+}
+
+#[event]
+pub struct ProjectApplicationApproved {
+    pub application_id: Pubkey,
+    pub project_registry_id: Pubkey,
+    pub approved_by: Pubkey,
+}
+
+#[event]
+pub struct ProjectApplicationRejected {
+    pub application_id: Pubkey,
+    pub developer: Pubkey,
+    pub forfeited_deposit: u64,
+    pub rejected_by: Pubkey,
+    pub reason: String,
+}
+
+#[event]
+pub struct ApplicationDepositUpdated {
+    pub registry_id: Pubkey,
+    pub application_deposit: u64,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct ForfeitedDepositsWithdrawn {
+    pub registry_id: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub withdrawn_by: Pubkey,
+}
+
 #[event]
 pub struct ProjectRegistered {
     pub project_registry_id: Pubkey,
     pub project_id: String,
     pub vintage_year: u16,
     pub project_developer: Pubkey,
+    pub project_type: ProjectType,
 }
 
 #[event]
@@ -608,6 +3027,51 @@ pub struct CreditsIssued {
     pub serial_number_prefix: String,
     pub quantity: u64,
     pub recipient: Pubkey,
+    pub buffer_contribution: u64,
+    pub share_of_proceeds: u64,
+    pub omge_cancelled: u64,
+}
+
+#[event]
+pub struct IssuanceLeviesConfigured {
+    pub registry_id: Pubkey,
+    pub share_of_proceeds_bps: u16,
+    pub omge_bps: u16,
+    pub adaptation_fund: Option<Pubkey>,
+}
+
+#[event]
+pub struct BufferPoolConfigured {
+    pub buffer_pool_id: Pubkey,
+    pub project_type: ProjectType,
+    pub buffer_percentage: u16,
+}
+
+#[event]
+pub struct BufferVaultInitialized {
+    pub buffer_vault: Pubkey,
+    pub project_registry: Pubkey,
+    pub credit_mint: Pubkey,
+}
+
+#[event]
+pub struct ReversalReported {
+    pub project_registry: Pubkey,
+    pub source_project_registry: Pubkey,
+    pub buffer_pool: Pubkey,
+    pub quantity: u64,
+    pub reason: String,
+    pub reported_by: Pubkey,
+}
+
+#[event]
+pub struct IssuanceCancelled {
+    pub issuance_id: Pubkey,
+    pub project_registry: Pubkey,
+    pub quantity: u64,
+    pub remaining: u64,
+    pub reason: String,
+    pub cancelled_by: Pubkey,
 }
 
 #[event]
@@ -626,6 +3090,34 @@ pub struct CreditsRetired {
     pub project_registry: Pubkey,
     pub quantity: u64,
     pub reason: String,
+    pub beneficiary_wallet: Option<Pubkey>,
+    pub use_type: RetirementUse,
+}
+
+#[event]
+pub struct RetirementCertificateIssued {
+    pub certificate_id: Pubkey,
+    pub retirement_id: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub quantity: u64,
+}
+
+#[event]
+pub struct RetirementClaimed {
+    pub claim_id: Pubkey,
+    pub retirement_id: Pubkey,
+    pub claimant: Pubkey,
+    pub reporting_year: u16,
+    pub quantity: u64,
+}
+
+#[event]
+pub struct AttributionTransferred {
+    pub retirement_id: Pubkey,
+    pub from_beneficiary: Pubkey,
+    pub to_beneficiary: Pubkey,
+    pub transferred_at: i64,
 }
 
 #[event]
@@ -637,6 +3129,37 @@ pub struct BatchCreated {
     pub vintage_end: i64,
 }
 
+#[event]
+pub struct CreditingPeriodRenewed {
+    pub project_registry_id: Pubkey,
+    pub verification_result: Pubkey,
+    pub crediting_period_start: i64,
+    pub crediting_period_end: i64,
+    pub renewals: u8,
+}
+
+#[event]
+pub struct ProjectTransferProposed {
+    pub project_registry_id: Pubkey,
+    pub current_developer: Pubkey,
+    pub pending_developer: Pubkey,
+}
+
+#[event]
+pub struct ProjectTransferApproved {
+    pub project_registry_id: Pubkey,
+    pub pending_developer: Pubkey,
+    pub approved_by: Pubkey,
+}
+
+#[event]
+pub struct ProjectOwnershipTransferred {
+    pub project_registry_id: Pubkey,
+    pub old_developer: Pubkey,
+    pub new_developer: Pubkey,
+    pub transferred_at: i64,
+}
+
 #[event]
 pub struct ProjectStatusUpdated {
     pub project_registry_id: Pubkey,
@@ -646,6 +3169,22 @@ pub struct ProjectStatusUpdated {
     pub updated_by: Pubkey,
 }
 
+#[event]
+pub struct CreditAccountFrozen {
+    pub project_registry: Pubkey,
+    pub token_account: Pubkey,
+    pub holder: Pubkey,
+    pub frozen_by: Pubkey,
+}
+
+#[event]
+pub struct CreditAccountThawed {
+    pub project_registry: Pubkey,
+    pub token_account: Pubkey,
+    pub holder: Pubkey,
+    pub thawed_by: Pubkey,
+}
+
 #[event]
 pub struct ProjectMetadataAdded {
     pub metadata_id: Pubkey,
@@ -661,10 +3200,8 @@ pub enum ErrorCode {
     BaseUriTooLong,
     #[msg("Project ID too long")]
     ProjectIdTooLong,
-    #[msg("Methodology too long")]
+    #[msg("Methodology ID too long")]
     MethodologyTooLong,
-    #[msg("Country code too long")]
-    CountryCodeTooLong,
     #[msg("Invalid vintage year")]
     InvalidVintageYear,
     #[msg("Serial number prefix too long")]
@@ -691,4 +3228,142 @@ pub enum ErrorCode {
     MetadataUriTooLong,
     #[msg("Description too long")]
     DescriptionTooLong,
+    #[msg("Certificate holder must be the beneficiary wallet or the owner")]
+    InvalidCertificateHolder,
+    #[msg("Invalid reporting year")]
+    InvalidReportingYear,
+    #[msg("Signer does not hold the retirement attribution")]
+    NotAttributionHolder,
+    #[msg("Attribution already claimed")]
+    AttributionAlreadyClaimed,
+    #[msg("Attribution already transferred")]
+    AttributionAlreadyTransferred,
+    #[msg("Invalid beneficiary")]
+    InvalidBeneficiary,
+    #[msg("Issuance not active")]
+    IssuanceNotActive,
+    #[msg("Cancellation exceeds outstanding issuance")]
+    CancellationExceedsIssuance,
+    #[msg("Registry has no burn authority over the source account")]
+    RegistryCannotBurn,
+    #[msg("Issuance does not belong to this project")]
+    IssuanceProjectMismatch,
+    #[msg("Invalid buffer percentage")]
+    InvalidBufferPercentage,
+    #[msg("Buffer pool required for this project type")]
+    BufferPoolRequired,
+    #[msg("Buffer vault required when credits are withheld")]
+    BufferVaultRequired,
+    #[msg("Buffer vault belongs to a different pool")]
+    BufferPoolMismatch,
+    #[msg("Insufficient buffer credits")]
+    InsufficientBufferCredits,
+    #[msg("Project is not active")]
+    ProjectNotActive,
+    #[msg("Project is not suspended or terminated")]
+    ProjectNotSuspended,
+    #[msg("Signer is not the registry authority")]
+    UnauthorizedAuthority,
+    #[msg("Project does not belong to this registry")]
+    ProjectRegistryMismatch,
+    #[msg("Mint is not the project's credit mint")]
+    CreditMintMismatch,
+    #[msg("Token account mint does not match the credit mint")]
+    TokenAccountMintMismatch,
+    #[msg("Token account is not owned by the expected wallet")]
+    TokenAccountOwnerMismatch,
+    #[msg("Signer is neither the project developer nor a metadata editor")]
+    UnauthorizedProjectSigner,
+    #[msg("Role assignment belongs to another registry")]
+    RoleRegistryMismatch,
+    #[msg("Admins cannot revoke their own admin role")]
+    CannotRevokeOwnAdmin,
+    #[msg("Signer is not the pending registry authority")]
+    NotPendingAuthority,
+    #[msg("Too many documentation URIs")]
+    TooManyDocumentationUris,
+    #[msg("Documentation URI too long")]
+    DocumentationUriTooLong,
+    #[msg("Developer does not match the application")]
+    ApplicationDeveloperMismatch,
+    #[msg("Rejection reason too long")]
+    RejectionReasonTooLong,
+    #[msg("No project transfer is pending")]
+    NoPendingProjectTransfer,
+    #[msg("Project transfer has not been approved by the registry")]
+    ProjectTransferNotApproved,
+    #[msg("Signer is not the pending project developer")]
+    NotPendingDeveloper,
+    #[msg("Project status transition is not allowed")]
+    InvalidStatusTransition,
+    #[msg("Issuance is paused while the project is under review")]
+    ProjectUnderReview,
+    #[msg("Methodology version too long")]
+    MethodologyVersionTooLong,
+    #[msg("Invalid crediting period limits")]
+    InvalidCreditingPeriodLimits,
+    #[msg("Methodology is deprecated")]
+    MethodologyDeprecated,
+    #[msg("Methodology does not cover this project type")]
+    MethodologySectorMismatch,
+    #[msg("Methodology belongs to another registry")]
+    MethodologyRegistryMismatch,
+    #[msg("Methodology does not match the application")]
+    MethodologyMismatch,
+    #[msg("Crediting period length is outside the methodology limits")]
+    InvalidCreditingPeriod,
+    #[msg("Vintage falls outside the project's crediting period")]
+    VintageOutsideCreditingPeriod,
+    #[msg("No crediting period renewals left under this methodology")]
+    CreditingPeriodRenewalsExhausted,
+    #[msg("Verification is not valid or predates the final year of the crediting period")]
+    StaleVerification,
+    #[msg("Verification result is for another project")]
+    VerificationProjectMismatch,
+    #[msg("Country code is not an ISO 3166-1 alpha-2 or alpha-3 code")]
+    InvalidCountryCode,
+    #[msg("Subdivision code is not an ISO 3166-2 code for the project country")]
+    InvalidSubdivisionCode,
+    #[msg("Country stats belong to another registry")]
+    CountryStatsRegistryMismatch,
+    #[msg("Signer is not the host country's designated national authority")]
+    UnauthorizedNationalAuthority,
+    #[msg("Authorized uses must list between one and three uses")]
+    InvalidAuthorizedUses,
+    #[msg("Project already has an Article 6 authorization")]
+    AlreadyAuthorized,
+    #[msg("Project is not authorized under Article 6")]
+    NotArticle6Authorized,
+    #[msg("Retirement use is not covered by the Article 6 authorization")]
+    UseNotAuthorized,
+    #[msg("Share of proceeds and OMGE together cannot exceed 100%")]
+    InvalidLevyPercentage,
+    #[msg("An adaptation fund is required to collect the share of proceeds")]
+    AdaptationFundRequired,
+    #[msg("Token account is not owned by the registry's adaptation fund")]
+    AdaptationFundMismatch,
+    #[msg("Buffer contribution and levies exceed the issued quantity")]
+    LeviesExceedIssuance,
+    #[msg("Verification has an open challenge")]
+    VerificationUnderChallenge,
+    #[msg("Retirement does not belong to this project")]
+    RetirementProjectMismatch,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Reversal exceeds the project's issued credits")]
+    ReversalExceedsIssuance,
+    #[msg("Pass exactly one of a new or the existing admin role")]
+    InvalidAdminRole,
+    #[msg("Amount exceeds the registry's forfeited deposits")]
+    InsufficientForfeitedDeposits,
+    #[msg("Verifier does not match the verification result")]
+    VerifierMismatch,
+    #[msg("Verifier is not currently accredited")]
+    VerifierNotAccredited,
+    #[msg("Verifier is not accredited for this project's sector or size")]
+    VerifierOutOfScope,
+    #[msg("Transfers of Article 6 authorized credits must name a destination country")]
+    DestinationCountryRequired,
+    #[msg("Buffer credits can only be cancelled through a reversal")]
+    BufferCreditsNotCancellable,
 }
\ No newline at end of file
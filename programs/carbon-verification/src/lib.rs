@@ -1,36 +1,417 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
 
 declare_id!("CarbVerify11111111111111111111111111111111");
 
+const MAX_REQUEST_VERIFIERS: usize = 5;
+
+// Programs whose project accounts can be submitted for verification
+const CARBON_REGISTRY_ID: Pubkey = anchor_lang::solana_program::pubkey!("CarbRegistry1111111111111111111111111111111");
+const CARBON_MARKETPLACE_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("CarbMktpLace11111111111111111111111111111111");
+
 #[program]
 pub mod carbon_verification {
     use super::*;
 
+    pub fn initialize_verification_config(ctx: Context<InitializeVerificationConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.verification_config;
+        config.authority = ctx.accounts.authority.key();
+        config.total_accreditation_bodies = 0;
+        config.credit_limits = [10_000, 100_000, 1_000_000, u64::MAX];
+        config.min_stake = 0;
+        config.slash_bps = 1000;
+        config.challenger_share_bps = 5000;
+        config.unbonding_period = 14 * 24 * 60 * 60;
+        config.challenge_bond = 100_000_000;
+        config.appeal_bond = 500_000_000;
+        config.appeal_window = 7 * 24 * 60 * 60;
+        config.treasury = None;
+        config.fee_holdback_bps = 2000;
+        config.fee_holdback_period = 90 * 24 * 60 * 60;
+        config.bump = *ctx.bumps.get("verification_config").unwrap();
+        Ok(())
+    }
+
+    pub fn set_certification_limits(
+        ctx: Context<UpdateVerificationConfig>,
+        credit_limits: [u64; 4],
+    ) -> Result<()> {
+        require!(
+            credit_limits.windows(2).all(|pair| pair[0] <= pair[1]),
+            ErrorCode::InvalidCertificationLimits
+        );
+        ctx.accounts.verification_config.credit_limits = credit_limits;
+
+        emit!(CertificationLimitsUpdated { credit_limits });
+
+        Ok(())
+    }
+
+    pub fn set_staking_params(
+        ctx: Context<UpdateVerificationConfig>,
+        min_stake: u64,
+        slash_bps: u16,
+        challenger_share_bps: u16,
+        unbonding_period: i64,
+    ) -> Result<()> {
+        require!(
+            slash_bps <= 10000 && challenger_share_bps <= 10000,
+            ErrorCode::InvalidStakingParams
+        );
+        require!(unbonding_period >= 0, ErrorCode::InvalidStakingParams);
+
+        let config = &mut ctx.accounts.verification_config;
+        config.min_stake = min_stake;
+        config.slash_bps = slash_bps;
+        config.challenger_share_bps = challenger_share_bps;
+        config.unbonding_period = unbonding_period;
+
+        emit!(StakingParamsUpdated {
+            min_stake,
+            slash_bps,
+            challenger_share_bps,
+            unbonding_period,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_fee_params(
+        ctx: Context<UpdateVerificationConfig>,
+        fee_holdback_bps: u16,
+        fee_holdback_period: i64,
+    ) -> Result<()> {
+        require!(fee_holdback_bps <= 10000, ErrorCode::InvalidFeeParams);
+        require!(fee_holdback_period >= 0, ErrorCode::InvalidFeeParams);
+
+        let config = &mut ctx.accounts.verification_config;
+        config.fee_holdback_bps = fee_holdback_bps;
+        config.fee_holdback_period = fee_holdback_period;
+
+        emit!(FeeParamsUpdated {
+            fee_holdback_bps,
+            fee_holdback_period,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_challenge_params(
+        ctx: Context<UpdateVerificationConfig>,
+        challenge_bond: u64,
+        appeal_bond: u64,
+        appeal_window: i64,
+        treasury: Option<Pubkey>,
+    ) -> Result<()> {
+        require!(appeal_bond > challenge_bond, ErrorCode::InvalidChallengeParams);
+        require!(appeal_window >= 0, ErrorCode::InvalidChallengeParams);
+
+        let config = &mut ctx.accounts.verification_config;
+        config.challenge_bond = challenge_bond;
+        config.appeal_bond = appeal_bond;
+        config.appeal_window = appeal_window;
+        config.treasury = treasury;
+
+        emit!(ChallengeParamsUpdated {
+            challenge_bond,
+            appeal_bond,
+            appeal_window,
+            treasury,
+        });
+
+        Ok(())
+    }
+
+    pub fn initialize_arbitration_panel(
+        ctx: Context<InitializeArbitrationPanel>,
+        tier: PanelTier,
+        members: Vec<Pubkey>,
+        quorum: u8,
+        voting_period: i64,
+    ) -> Result<()> {
+        let panel = &mut ctx.accounts.arbitration_panel;
+        panel.tier = tier.clone();
+        panel.bump = *ctx.bumps.get("arbitration_panel").unwrap();
+        panel.configure(members.clone(), quorum, voting_period)?;
+
+        emit!(ArbitrationPanelUpdated {
+            panel_id: panel.key(),
+            tier,
+            members,
+            quorum,
+            voting_period,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_arbitration_panel(
+        ctx: Context<UpdateArbitrationPanel>,
+        members: Vec<Pubkey>,
+        quorum: u8,
+        voting_period: i64,
+    ) -> Result<()> {
+        let panel = &mut ctx.accounts.arbitration_panel;
+        panel.configure(members.clone(), quorum, voting_period)?;
+
+        emit!(ArbitrationPanelUpdated {
+            panel_id: panel.key(),
+            tier: panel.tier.clone(),
+            members,
+            quorum,
+            voting_period,
+        });
+
+        Ok(())
+    }
+
+    pub fn register_accreditation_body(
+        ctx: Context<RegisterAccreditationBody>,
+        body_authority: Pubkey,
+        name: String,
+    ) -> Result<()> {
+        require!(name.len() <= 64, ErrorCode::AccreditationBodyTooLong);
+
+        let accreditation_body = &mut ctx.accounts.accreditation_body;
+        accreditation_body.authority = body_authority;
+        accreditation_body.name = name.clone();
+        accreditation_body.is_active = true;
+        accreditation_body.total_verifiers_accredited = 0;
+        accreditation_body.created_at = Clock::get()?.unix_timestamp;
+        accreditation_body.bump = *ctx.bumps.get("accreditation_body").unwrap();
+
+        ctx.accounts.verification_config.total_accreditation_bodies += 1;
+
+        emit!(AccreditationBodyRegistered {
+            accreditation_body_id: accreditation_body.key(),
+            authority: body_authority,
+            name,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_accreditation_body_status(
+        ctx: Context<SetAccreditationBodyStatus>,
+        is_active: bool,
+    ) -> Result<()> {
+        let accreditation_body = &mut ctx.accounts.accreditation_body;
+        accreditation_body.is_active = is_active;
+
+        emit!(AccreditationBodyStatusUpdated {
+            accreditation_body_id: accreditation_body.key(),
+            is_active,
+        });
+
+        Ok(())
+    }
+
     pub fn initialize_verifier(
         ctx: Context<InitializeVerifier>,
+        verifier_seed: Pubkey,
         verifier_name: String,
         certification_level: CertificationLevel,
-        accreditation_body: String,
+        accredited_sectors: Vec<ProjectType>,
+        accredited_until: i64,
     ) -> Result<()> {
         require!(verifier_name.len() <= 64, ErrorCode::VerifierNameTooLong);
-        require!(accreditation_body.len() <= 64, ErrorCode::AccreditationBodyTooLong);
+        require!(
+            !accredited_sectors.is_empty() && accredited_sectors.len() <= 8,
+            ErrorCode::InvalidAccreditedSectors
+        );
+        require!(
+            accredited_until > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidAccreditationPeriod
+        );
 
         let verifier = &mut ctx.accounts.verifier;
         verifier.authority = ctx.accounts.authority.key();
+        verifier.pending_authority = None;
+        verifier.verifier_seed = verifier_seed;
         verifier.verifier_name = verifier_name.clone();
-        verifier.certification_level = certification_level;
-        verifier.accreditation_body = accreditation_body;
+        verifier.certification_level = certification_level.clone();
+        verifier.accreditation_body = ctx.accounts.accreditation_body.key();
+        verifier.accredited_sectors = accredited_sectors;
+        verifier.accreditation_status = AccreditationStatus::Active;
+        verifier.accredited_until = accredited_until;
         verifier.is_active = true;
         verifier.total_projects_verified = 0;
         verifier.total_credits_verified = 0;
+        verifier.stake = 0;
+        verifier.unbonding_amount = 0;
+        verifier.unbonding_available_at = None;
+        verifier.total_slashed = 0;
+        verifier.open_challenges = 0;
         verifier.created_at = Clock::get()?.unix_timestamp;
         verifier.bump = *ctx.bumps.get("verifier").unwrap();
 
+        let stake_vault = &mut ctx.accounts.stake_vault;
+        stake_vault.verifier = verifier.key();
+        stake_vault.bump = *ctx.bumps.get("stake_vault").unwrap();
+
+        ctx.accounts.accreditation_body.total_verifiers_accredited += 1;
+
         emit!(VerifierInitialized {
             verifier_id: verifier.key(),
             authority: verifier.authority,
             verifier_name,
             certification_level,
+            accreditation_body: verifier.accreditation_body,
+        });
+
+        Ok(())
+    }
+
+    pub fn deposit_stake(ctx: Context<DepositStake>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidStakeAmount);
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(transfer_ctx, amount)?;
+
+        let verifier = &mut ctx.accounts.verifier;
+        verifier.stake += amount;
+
+        emit!(StakeDeposited {
+            verifier_id: verifier.key(),
+            amount,
+            total_stake: verifier.stake,
+        });
+
+        Ok(())
+    }
+
+    pub fn request_unbonding(ctx: Context<RequestUnbonding>, amount: u64) -> Result<()> {
+        let verifier = &mut ctx.accounts.verifier;
+        require!(amount > 0 && amount <= verifier.stake, ErrorCode::InvalidStakeAmount);
+
+        // Unbonding stake stays slashable until it is withdrawn. Unbonding is pooled, so each request
+        // restarts the unbonding period for everything already unbonding
+        let available_at = Clock::get()?
+            .unix_timestamp
+            .checked_add(ctx.accounts.verification_config.unbonding_period)
+            .ok_or(ErrorCode::MathOverflow)?;
+        verifier.stake -= amount;
+        verifier.unbonding_amount = verifier
+            .unbonding_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        verifier.unbonding_available_at = Some(available_at);
+
+        emit!(UnbondingRequested {
+            verifier_id: verifier.key(),
+            amount,
+            available_at,
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_unbonded(ctx: Context<WithdrawUnbonded>) -> Result<()> {
+        let verifier = &mut ctx.accounts.verifier;
+        let available_at = verifier.unbonding_available_at.ok_or(ErrorCode::NothingUnbonding)?;
+        require!(Clock::get()?.unix_timestamp >= available_at, ErrorCode::UnbondingPeriodActive);
+        // Stake stays bonded until every challenge against the verifier's results is settled
+        require!(verifier.open_challenges == 0, ErrorCode::ChallengesPending);
+
+        let amount = verifier.unbonding_amount;
+        verifier.unbonding_amount = 0;
+        verifier.unbonding_available_at = None;
+
+        if amount > 0 {
+            **ctx.accounts.stake_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+            **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+        }
+
+        emit!(StakeWithdrawn {
+            verifier_id: verifier.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn distribute_compensation(ctx: Context<DistributeCompensation>, amount: u64) -> Result<()> {
+        let slash_record = &mut ctx.accounts.slash_record;
+        require!(
+            amount > 0 && amount <= slash_record.compensation_amount - slash_record.compensation_distributed,
+            ErrorCode::InvalidStakeAmount
+        );
+        slash_record.compensation_distributed += amount;
+
+        **slash_record.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        emit!(CompensationDistributed {
+            slash_record_id: slash_record.key(),
+            recipient: ctx.accounts.recipient.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_verifier_accreditation(
+        ctx: Context<SetVerifierAccreditation>,
+        status: AccreditationStatus,
+        accredited_until: i64,
+    ) -> Result<()> {
+        let verifier = &mut ctx.accounts.verifier;
+        require!(
+            verifier.accreditation_status != AccreditationStatus::Revoked,
+            ErrorCode::AccreditationRevoked
+        );
+        require!(
+            status != AccreditationStatus::Active || accredited_until > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidAccreditationPeriod
+        );
+
+        verifier.accreditation_status = status.clone();
+        verifier.accredited_until = accredited_until;
+
+        emit!(VerifierAccreditationUpdated {
+            verifier_id: verifier.key(),
+            accreditation_body: verifier.accreditation_body,
+            status,
+            accredited_until,
+        });
+
+        Ok(())
+    }
+
+    pub fn propose_verifier_authority(
+        ctx: Context<ProposeVerifierAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        let verifier = &mut ctx.accounts.verifier;
+        verifier.pending_authority = Some(new_authority);
+
+        emit!(VerifierAuthorityProposed {
+            verifier_id: verifier.key(),
+            current_authority: verifier.authority,
+            pending_authority: new_authority,
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_verifier_authority(ctx: Context<AcceptVerifierAuthority>) -> Result<()> {
+        let verifier = &mut ctx.accounts.verifier;
+        let old_authority = verifier.authority;
+        verifier.authority = ctx.accounts.new_authority.key();
+        verifier.pending_authority = None;
+
+        emit!(VerifierAuthorityAccepted {
+            verifier_id: verifier.key(),
+            old_authority,
+            new_authority: verifier.authority,
         });
 
         Ok(())
@@ -42,32 +423,176 @@ pub mod carbon_verification {
         verification_type: VerificationType,
         documentation_uri: String,
         estimated_credits: u64,
+        co_verifiers: Vec<Pubkey>,
+        required_submissions: u8,
+        aggregation: CreditAggregation,
+        fee_amount: u64,
     ) -> Result<()> {
         require!(documentation_uri.len() <= 200, ErrorCode::DocumentationUriTooLong);
         require!(estimated_credits > 0, ErrorCode::InvalidCreditAmount);
+        require!(co_verifiers.len() < MAX_REQUEST_VERIFIERS, ErrorCode::TooManyVerifiers);
+        require_keys_eq!(project_key, ctx.accounts.project.key(), ErrorCode::ProjectMismatch);
+        // The sector comes from the project account itself, not from the requester
+        let project_type = project_type_of(&ctx.accounts.project)?;
+        let verifier = &ctx.accounts.verifier;
+        verifier.require_accredited(&ctx.accounts.accreditation_body)?;
+        verifier.require_in_scope(&ctx.accounts.verification_config, &project_type, estimated_credits)?;
+        require!(
+            verifier.stake >= ctx.accounts.verification_config.min_stake,
+            ErrorCode::InsufficientStake
+        );
+
+        // Co-verifiers are checked for accreditation and scope when they conduct
+        let mut verifiers = vec![ctx.accounts.verifier.key()];
+        for co_verifier in co_verifiers {
+            require!(!verifiers.contains(&co_verifier), ErrorCode::DuplicateVerifier);
+            verifiers.push(co_verifier);
+        }
+        require!(
+            required_submissions > 0 && required_submissions as usize <= verifiers.len(),
+            ErrorCode::InvalidSubmissionThreshold
+        );
 
         let request = &mut ctx.accounts.verification_request;
         request.project = project_key;
         request.requester = ctx.accounts.requester.key();
-        request.verifier = ctx.accounts.verifier.key();
-        request.verification_type = verification_type;
+        request.verifiers = verifiers;
+        request.declines = Vec::new();
+        request.required_submissions = required_submissions;
+        request.aggregation = aggregation;
+        request.submissions = Vec::new();
+        request.acceptances = Vec::new();
+        request.findings = None;
+        request.fee_mint = ctx.accounts.fee_mint.as_ref().map(|mint| mint.key());
+        request.fee_amount = fee_amount;
+        request.fee_holdback_until = None;
+        request.project_type = project_type;
+        request.verification_type = verification_type.clone();
         request.documentation_uri = documentation_uri.clone();
         request.estimated_credits = estimated_credits;
         request.status = VerificationStatus::Pending;
         request.submitted_at = Clock::get()?.unix_timestamp;
         request.bump = *ctx.bumps.get("verification_request").unwrap();
 
+        // The fee is escrowed in a vault owned by the request until the verification is done
+        if fee_amount > 0 {
+            require!(ctx.accounts.fee_vault.is_some(), ErrorCode::FeeAccountsRequired);
+            let requester_token_account = ctx
+                .accounts
+                .requester_token_account
+                .as_ref()
+                .ok_or(ErrorCode::FeeAccountsRequired)?;
+            let token_program = ctx.accounts.token_program.as_ref().ok_or(ErrorCode::FeeAccountsRequired)?;
+            let transfer_ctx = CpiContext::new(
+                token_program.to_account_info(),
+                Transfer {
+                    from: requester_token_account.to_account_info(),
+                    to: ctx.accounts.fee_vault.as_ref().unwrap().to_account_info(),
+                    authority: ctx.accounts.requester.to_account_info(),
+                },
+            );
+            token::transfer(transfer_ctx, fee_amount)?;
+        }
+
+        let request = &ctx.accounts.verification_request;
         emit!(VerificationRequestSubmitted {
             request_id: request.key(),
             project: project_key,
             requester: request.requester,
-            verifier: request.verifier,
+            verifiers: request.verifiers.clone(),
+            required_submissions,
             verification_type,
+            fee_mint: request.fee_mint,
+            fee_amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_request(
+        ctx: Context<AcceptRequest>,
+        fee_quote: Option<u64>,
+        deadline: Option<i64>,
+    ) -> Result<()> {
+        let verifier = &ctx.accounts.verifier;
+        verifier.require_accredited(&ctx.accounts.accreditation_body)?;
+        let request = &mut ctx.accounts.verification_request;
+        verifier.require_in_scope(&ctx.accounts.verification_config, &request.project_type, request.estimated_credits)?;
+        require!(request.is_open(), ErrorCode::RequestNotOpen);
+        require!(request.verifiers.contains(&verifier.key()), ErrorCode::NotRequestVerifier);
+        require!(!request.declines.contains(&verifier.key()), ErrorCode::VerifierDeclined);
+        require!(request.acceptance(&verifier.key()).is_none(), ErrorCode::AlreadyAccepted);
+        require!(
+            verifier.stake >= ctx.accounts.verification_config.min_stake,
+            ErrorCode::InsufficientStake
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(deadline.is_none_or(|deadline| deadline > now), ErrorCode::InvalidDeadline);
+
+        request.acceptances.push(VerifierAcceptance {
+            verifier: verifier.key(),
+            fee_quote,
+            deadline,
+            accepted_at: now,
+        });
+        request.status = VerificationStatus::InProgress;
+
+        emit!(RequestAccepted {
+            request_id: request.key(),
+            verifier: verifier.key(),
+            fee_quote,
+            deadline,
         });
 
         Ok(())
     }
 
+    pub fn decline_request(ctx: Context<RespondToRequest>, reason: String) -> Result<()> {
+        require!(reason.len() <= 500, ErrorCode::FindingsTooLong);
+
+        let verifier_key = ctx.accounts.verifier.key();
+        let request = &mut ctx.accounts.verification_request;
+        require!(request.is_open(), ErrorCode::RequestNotOpen);
+        require!(request.verifiers.contains(&verifier_key), ErrorCode::NotRequestVerifier);
+        require!(!request.declines.contains(&verifier_key), ErrorCode::VerifierDeclined);
+        require!(
+            !request.submissions.iter().any(|submission| submission.verifier == verifier_key),
+            ErrorCode::AlreadySubmitted
+        );
+
+        // The request is declined once too few verifiers remain to reach the threshold
+        request.declines.push(verifier_key);
+        let remaining = request.verifiers.len() - request.declines.len();
+        if remaining < request.required_submissions as usize {
+            request.status = VerificationStatus::Declined;
+            request.completed_at = Some(Clock::get()?.unix_timestamp);
+        } else if request.submissions.is_empty()
+            && !request.verifiers.iter().any(|verifier| request.acceptance(verifier).is_some())
+        {
+            request.status = VerificationStatus::Pending;
+        }
+
+        emit!(RequestDeclined {
+            request_id: request.key(),
+            verifier: verifier_key,
+            reason,
+            status: request.status.clone(),
+        });
+
+        if request.status == VerificationStatus::Declined {
+            refund_fee_escrow(
+                &ctx.accounts.verification_request,
+                &ctx.accounts.fee_vault,
+                &ctx.accounts.requester_token_account,
+                ctx.accounts.requester.as_ref().map(|requester| requester.to_account_info()),
+                &ctx.accounts.token_program,
+                0,
+            )?;
+        }
+
+        Ok(())
+    }
+
     pub fn conduct_verification(
         ctx: Context<ConductVerification>,
         verified_credits: u64,
@@ -77,37 +602,313 @@ pub mod carbon_verification {
         require!(verification_notes.len() <= 500, ErrorCode::VerificationNotesTooLong);
         require!(compliance_score <= 100, ErrorCode::InvalidComplianceScore);
 
+        ctx.accounts.verifier.require_accredited(&ctx.accounts.accreditation_body)?;
+        ctx.accounts.verifier.require_in_scope(
+            &ctx.accounts.verification_config,
+            &ctx.accounts.verification_request.project_type,
+            verified_credits,
+        )?;
+
         let request = &mut ctx.accounts.verification_request;
-        require!(request.status == VerificationStatus::Pending, ErrorCode::RequestNotPending);
+        require!(request.status == VerificationStatus::InProgress, ErrorCode::RequestNotOpen);
+        let verifier_key = ctx.accounts.verifier.key();
+        require!(request.verifiers.contains(&verifier_key), ErrorCode::NotRequestVerifier);
+        let acceptance = request.acceptance(&verifier_key).ok_or(ErrorCode::RequestNotAccepted)?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            acceptance.deadline.is_none_or(|deadline| now <= deadline),
+            ErrorCode::DeadlinePassed
+        );
+        require!(
+            !request.submissions.iter().any(|submission| submission.verifier == verifier_key),
+            ErrorCode::AlreadySubmitted
+        );
+        require!(
+            ctx.accounts.verifier.stake >= ctx.accounts.verification_config.min_stake,
+            ErrorCode::InsufficientStake
+        );
+        // Verifiers under one authority would count the same party towards the threshold twice
+        let verifier_authority = ctx.accounts.verifier.authority;
+        require!(
+            !request.submissions.iter().any(|submission| submission.authority == verifier_authority),
+            ErrorCode::DuplicateVerifierAuthority
+        );
 
-        let verification = &mut ctx.accounts.verification_result;
+        // Each submission is paid its share of the fee, less a holdback kept against challenges
+        let fee_share = request.fee_share(request.submissions.len());
+        let holdback_bps = ctx.accounts.verification_config.fee_holdback_bps as u64;
+        let fee_held_back = fee_share.checked_mul(holdback_bps).ok_or(ErrorCode::MathOverflow)? / 10000;
+        let fee_paid = fee_share - fee_held_back;
+
+        request.submissions.push(VerifierSubmission {
+            verifier: verifier_key,
+            authority: verifier_authority,
+            verified_credits,
+            compliance_score,
+            submitted_at: now,
+            fee_paid,
+            fee_held_back,
+        });
+
+        if fee_paid > 0 {
+            transfer_from_fee_vault(
+                &ctx.accounts.verification_request,
+                &ctx.accounts.fee_vault,
+                &ctx.accounts.verifier_token_account,
+                &ctx.accounts.token_program,
+                fee_paid,
+            )?;
+            emit!(FeeReleased {
+                request_id: ctx.accounts.verification_request.key(),
+                verifier: verifier_key,
+                amount: fee_paid,
+                held_back: fee_held_back,
+            });
+        }
+        let request = &mut ctx.accounts.verification_request;
+
+        let verifier = &mut ctx.accounts.verifier;
+        verifier.total_projects_verified += 1;
+        verifier.total_credits_verified += verified_credits;
+
+        emit!(VerificationSubmitted {
+            request_id: request.key(),
+            verifier: verifier_key,
+            verified_credits,
+            compliance_score,
+            verification_notes: verification_notes.clone(),
+            submissions: request.submissions.len() as u8,
+            required_submissions: request.required_submissions,
+        });
+
+        // The result is only created once enough verifiers have submitted
+        if (request.submissions.len() as u8) < request.required_submissions {
+            require!(ctx.accounts.verification_result.is_none(), ErrorCode::ConsensusNotReached);
+            return Ok(());
+        }
+
+        let (verified_credits, compliance_score) = request.aggregate();
+        let verification = ctx
+            .accounts
+            .verification_result
+            .as_mut()
+            .ok_or(ErrorCode::VerificationResultRequired)?;
         verification.request = request.key();
-        verification.verifier = ctx.accounts.verifier.key();
+        verification.verifier = request.submissions[0].verifier;
+        verification.verifiers = request.submissions.iter().map(|submission| submission.verifier).collect();
+        verification.verifier_authorities = request.submissions.iter().map(|submission| submission.authority).collect();
         verification.project = request.project;
         verification.verified_credits = verified_credits;
-        verification.verification_notes = verification_notes.clone();
+        verification.verification_notes = verification_notes;
         verification.compliance_score = compliance_score;
-        verification.methodology_used = request.verification_type;
-        verification.verified_at = Clock::get()?.unix_timestamp;
+        verification.methodology_used = request.verification_type.clone();
+        verification.verified_at = now;
         verification.is_valid = true;
+        verification.under_challenge = false;
+        verification.open_challenges = 0;
+        verification.upheld_challenges = 0;
         verification.bump = *ctx.bumps.get("verification_result").unwrap();
 
         request.status = VerificationStatus::Completed;
-        request.completed_at = Some(Clock::get()?.unix_timestamp);
-
-        let verifier = &mut ctx.accounts.verifier;
-        verifier.total_projects_verified += 1;
-        verifier.total_credits_verified += verified_credits;
+        request.completed_at = Some(now);
+        if request.fee_amount > 0 {
+            request.fee_holdback_until = Some(now + ctx.accounts.verification_config.fee_holdback_period);
+        }
 
         emit!(VerificationCompleted {
             verification_id: verification.key(),
             request_id: request.key(),
             project: request.project,
-            verifier: verification.verifier,
+            verifiers: verification.verifiers.clone(),
             verified_credits,
             compliance_score,
         });
 
+        // Without a holdback the fee has been paid out in full
+        if request.fee_amount > 0 && request.unclaimed_holdback() == 0 {
+            close_fee_vault(
+                &ctx.accounts.verification_request,
+                &ctx.accounts.fee_vault,
+                ctx.accounts.requester.as_ref().map(|requester| requester.to_account_info()),
+                &ctx.accounts.token_program,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn reject_verification(ctx: Context<RespondToRequest>, findings: String) -> Result<()> {
+        require!(findings.len() <= 500, ErrorCode::FindingsTooLong);
+
+        let verifier_key = ctx.accounts.verifier.key();
+        let request = &mut ctx.accounts.verification_request;
+        require!(request.status == VerificationStatus::InProgress, ErrorCode::RequestNotOpen);
+        require!(request.acceptance(&verifier_key).is_some(), ErrorCode::RequestNotAccepted);
+        // A verifier that submitted has already been paid its share
+        require!(
+            !request.submissions.iter().any(|submission| submission.verifier == verifier_key),
+            ErrorCode::AlreadySubmitted
+        );
+
+        // Any accepted verifier's negative finding rejects the whole request
+        request.status = VerificationStatus::Rejected;
+        request.findings = Some(findings.clone());
+        request.completed_at = Some(Clock::get()?.unix_timestamp);
+
+        emit!(VerificationRejected {
+            request_id: request.key(),
+            project: request.project,
+            verifier: verifier_key,
+            findings,
+        });
+
+        // The rejecting verifier is paid for its work; there is no result to hold back against
+        let fee_paid = request.fee_share(request.submissions.len());
+        if fee_paid > 0 {
+            transfer_from_fee_vault(
+                &ctx.accounts.verification_request,
+                &ctx.accounts.fee_vault,
+                &ctx.accounts.verifier_token_account,
+                &ctx.accounts.token_program,
+                fee_paid,
+            )?;
+            emit!(FeeReleased {
+                request_id: ctx.accounts.verification_request.key(),
+                verifier: verifier_key,
+                amount: fee_paid,
+                held_back: 0,
+            });
+        }
+        refund_fee_escrow(
+            &ctx.accounts.verification_request,
+            &ctx.accounts.fee_vault,
+            &ctx.accounts.requester_token_account,
+            ctx.accounts.requester.as_ref().map(|requester| requester.to_account_info()),
+            &ctx.accounts.token_program,
+            fee_paid,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn withdraw_request(ctx: Context<CloseRequest>) -> Result<()> {
+        let request = &mut ctx.accounts.verification_request;
+        require!(request.is_open(), ErrorCode::RequestNotOpen);
+
+        request.status = VerificationStatus::Withdrawn;
+        request.completed_at = Some(Clock::get()?.unix_timestamp);
+
+        emit!(RequestWithdrawn {
+            request_id: request.key(),
+            project: request.project,
+            requester: request.requester,
+        });
+
+        refund_fee_escrow(
+            &ctx.accounts.verification_request,
+            &ctx.accounts.fee_vault,
+            &ctx.accounts.requester_token_account,
+            Some(ctx.accounts.requester.to_account_info()),
+            &ctx.accounts.token_program,
+            0,
+        )
+    }
+
+    pub fn expire_request(ctx: Context<CloseRequest>) -> Result<()> {
+        let request = &mut ctx.accounts.verification_request;
+        require!(request.status == VerificationStatus::InProgress, ErrorCode::RequestNotOpen);
+
+        // Expires once any accepted verifier misses its deadline without submitting
+        let now = Clock::get()?.unix_timestamp;
+        let missed = request.acceptances.iter().any(|acceptance| {
+            acceptance.deadline.is_some_and(|deadline| now > deadline)
+                && !request.declines.contains(&acceptance.verifier)
+                && !request.submissions.iter().any(|submission| submission.verifier == acceptance.verifier)
+        });
+        require!(missed, ErrorCode::DeadlineNotPassed);
+
+        request.status = VerificationStatus::Expired;
+        request.completed_at = Some(now);
+
+        emit!(RequestExpired {
+            request_id: request.key(),
+            project: request.project,
+            requester: request.requester,
+        });
+
+        refund_fee_escrow(
+            &ctx.accounts.verification_request,
+            &ctx.accounts.fee_vault,
+            &ctx.accounts.requester_token_account,
+            Some(ctx.accounts.requester.to_account_info()),
+            &ctx.accounts.token_program,
+            0,
+        )
+    }
+
+    pub fn claim_fee_holdback(ctx: Context<ClaimFeeHoldback>) -> Result<()> {
+        let verifier_key = ctx.accounts.verifier.key();
+        let request = &mut ctx.accounts.verification_request;
+        let now = Clock::get()?.unix_timestamp;
+
+        // A completed verification holds the fee back until the period passes with no challenge pending or upheld
+        match request.status {
+            VerificationStatus::Completed => {
+                let verification = ctx
+                    .accounts
+                    .verification_result
+                    .as_ref()
+                    .ok_or(ErrorCode::VerificationResultRequired)?;
+                require!(
+                    request.fee_holdback_until.is_some_and(|until| now >= until),
+                    ErrorCode::FeeHoldbackActive
+                );
+                require!(
+                    verification.open_challenges == 0 && verification.upheld_challenges == 0,
+                    ErrorCode::FeeHoldbackActive
+                );
+            }
+            VerificationStatus::Pending | VerificationStatus::InProgress => {
+                return err!(ErrorCode::FeeHoldbackActive);
+            }
+            _ => {}
+        }
+
+        let submission = request
+            .submissions
+            .iter_mut()
+            .find(|submission| submission.verifier == verifier_key)
+            .ok_or(ErrorCode::NotRequestVerifier)?;
+        let amount = submission.fee_held_back;
+        require!(amount > 0, ErrorCode::NoFeeHoldback);
+        submission.fee_held_back = 0;
+        submission.fee_paid += amount;
+
+        transfer_from_fee_vault(
+            &ctx.accounts.verification_request,
+            &ctx.accounts.fee_vault,
+            &ctx.accounts.verifier_token_account,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        emit!(FeeReleased {
+            request_id: ctx.accounts.verification_request.key(),
+            verifier: verifier_key,
+            amount,
+            held_back: 0,
+        });
+
+        // The last holdback empties the vault
+        if ctx.accounts.verification_request.unclaimed_holdback() == 0 {
+            close_fee_vault(
+                &ctx.accounts.verification_request,
+                &ctx.accounts.fee_vault,
+                ctx.accounts.requester.as_ref().map(|requester| requester.to_account_info()),
+                &ctx.accounts.token_program,
+            )?;
+        }
+
         Ok(())
     }
 
@@ -118,78 +919,491 @@ pub mod carbon_verification {
     ) -> Result<()> {
         require!(challenge_reason.len() <= 500, ErrorCode::ChallengeReasonTooLong);
         require!(evidence_uri.len() <= 200, ErrorCode::EvidenceUriTooLong);
+        require!(ctx.accounts.verification_result.is_valid, ErrorCode::VerificationNotValid);
+
+        // The bond is held on the challenge account until resolution
+        let bond = ctx.accounts.verification_config.challenge_bond;
+        if bond > 0 {
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.challenger.to_account_info(),
+                    to: ctx.accounts.challenge.to_account_info(),
+                },
+            );
+            system_program::transfer(transfer_ctx, bond)?;
+        }
 
         let challenge = &mut ctx.accounts.challenge;
         challenge.verification = ctx.accounts.verification_result.key();
         challenge.challenger = ctx.accounts.challenger.key();
         challenge.challenge_reason = challenge_reason.clone();
         challenge.evidence_uri = evidence_uri;
+        challenge.bond = bond;
         challenge.status = ChallengeStatus::Open;
         challenge.submitted_at = Clock::get()?.unix_timestamp;
+        challenge.panel = ctx.accounts.arbitration_panel.key();
+        challenge.panel_members = ctx.accounts.arbitration_panel.members.clone();
+        challenge.quorum = ctx.accounts.arbitration_panel.quorum;
+        challenge.voting_ends_at = challenge.submitted_at + ctx.accounts.arbitration_panel.voting_period;
+        challenge.votes_upheld = 0;
+        challenge.votes_rejected = 0;
+        challenge.appeal_deadline = None;
+        challenge.appeal = None;
+        challenge.settled = false;
         challenge.bump = *ctx.bumps.get("challenge").unwrap();
 
+        // The verification stays valid while the challenge is open
         let verification = &mut ctx.accounts.verification_result;
-        verification.is_valid = false;
+        verification.open_challenges += 1;
+        verification.under_challenge = true;
+
+        // Co-verifiers are passed as remaining accounts, in result order
+        ctx.accounts.verifier.open_challenge()?;
+        update_co_verifiers(verification, ctx.remaining_accounts, Verifier::open_challenge)?;
 
         emit!(VerificationChallenged {
             challenge_id: challenge.key(),
             verification_id: verification.key(),
             challenger: challenge.challenger,
             reason: challenge_reason,
+            bond,
         });
 
         Ok(())
     }
 
-    pub fn resolve_challenge(
-        ctx: Context<ResolveChallenge>,
-        resolution: ChallengeResolution,
+    pub fn cast_challenge_vote(
+        ctx: Context<CastChallengeVote>,
+        vote: ChallengeResolution,
         resolution_notes: String,
     ) -> Result<()> {
         require!(resolution_notes.len() <= 500, ErrorCode::ResolutionNotesTooLong);
 
         let challenge = &mut ctx.accounts.challenge;
+        require!(
+            challenge.panel_members.contains(&ctx.accounts.member.key()),
+            ErrorCode::NotPanelMember
+        );
         require!(challenge.status == ChallengeStatus::Open, ErrorCode::ChallengeNotOpen);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < challenge.voting_ends_at, ErrorCode::VotingClosed);
 
-        challenge.status = match resolution {
-            ChallengeResolution::Upheld => ChallengeStatus::Upheld,
-            ChallengeResolution::Rejected => ChallengeStatus::Rejected,
+        let challenge_vote = &mut ctx.accounts.challenge_vote;
+        challenge_vote.challenge = challenge.key();
+        challenge_vote.member = ctx.accounts.member.key();
+        challenge_vote.vote = vote.clone();
+        challenge_vote.voted_at = now;
+        challenge_vote.bump = *ctx.bumps.get("challenge_vote").unwrap();
+
+        match vote {
+            ChallengeResolution::Upheld => challenge.votes_upheld += 1,
+            ChallengeResolution::Rejected => challenge.votes_rejected += 1,
+        }
+
+        emit!(ChallengeVoteCast {
+            challenge_id: challenge.key(),
+            member: challenge_vote.member,
+            vote,
+            votes_upheld: challenge.votes_upheld,
+            votes_rejected: challenge.votes_rejected,
+        });
+
+        // The resolution is applied by the vote that brings either side to quorum
+        let resolution = if challenge.votes_upheld >= challenge.quorum {
+            ChallengeResolution::Upheld
+        } else if challenge.votes_rejected >= challenge.quorum {
+            ChallengeResolution::Rejected
+        } else {
+            return Ok(());
         };
-        challenge.resolved_at = Some(Clock::get()?.unix_timestamp);
-        challenge.resolution_notes = Some(resolution_notes.clone());
+
+        challenge.status = resolution.status();
+        challenge.resolved_at = Some(now);
+        challenge.resolution_notes = Some(resolution_notes);
+        challenge.appeal_deadline = Some(now + ctx.accounts.verification_config.appeal_window);
 
         let verification = &mut ctx.accounts.verification_result;
-        verification.is_valid = matches!(resolution, ChallengeResolution::Rejected);
+        if resolution == ChallengeResolution::Upheld {
+            verification.upheld_challenges += 1;
+            verification.is_valid = false;
+        }
 
         emit!(ChallengeResolved {
             challenge_id: challenge.key(),
             verification_id: verification.key(),
             resolution,
-            resolver: ctx.accounts.resolver.key(),
+            resolver: ctx.accounts.member.key(),
+            appeal_deadline: challenge.appeal_deadline.unwrap(),
         });
 
         Ok(())
     }
 
-    pub fn update_verifier_status(
-        ctx: Context<UpdateVerifierStatus>,
-        is_active: bool,
+    pub fn file_appeal(
+        ctx: Context<FileAppeal>,
+        appeal_reason: String,
+        evidence_uri: String,
     ) -> Result<()> {
-        let verifier = &mut ctx.accounts.verifier;
-        let old_status = verifier.is_active;
-        verifier.is_active = is_active;
+        require!(appeal_reason.len() <= 500, ErrorCode::ChallengeReasonTooLong);
+        require!(evidence_uri.len() <= 200, ErrorCode::EvidenceUriTooLong);
 
-        emit!(VerifierStatusUpdated {
-            verifier_id: verifier.key(),
-            old_status,
-            new_status: is_active,
-            updated_by: ctx.accounts.authority.key(),
+        let appealed_ruling = ctx.accounts.challenge.status.resolution().ok_or(ErrorCode::ChallengeNotResolved)?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts.challenge.appeal_deadline.is_some_and(|deadline| now < deadline),
+            ErrorCode::AppealWindowClosed
+        );
+        let appellant = ctx.accounts.appellant.key();
+        require!(
+            appellant == ctx.accounts.challenge.challenger || appellant == ctx.accounts.verifier.authority,
+            ErrorCode::NotChallengeParty
+        );
+        // Nobody who ruled at first instance may sit on the appeal
+        let panel = &ctx.accounts.arbitration_panel;
+        require!(
+            !panel.members.iter().any(|member| ctx.accounts.challenge.panel_members.contains(member)),
+            ErrorCode::AppealPanelOverlap
+        );
+
+        let bond = ctx.accounts.verification_config.appeal_bond;
+        if bond > 0 {
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.appellant.to_account_info(),
+                    to: ctx.accounts.appeal.to_account_info(),
+                },
+            );
+            system_program::transfer(transfer_ctx, bond)?;
+        }
+
+        let appeal = &mut ctx.accounts.appeal;
+        appeal.challenge = ctx.accounts.challenge.key();
+        appeal.appellant = appellant;
+        appeal.appeal_reason = appeal_reason;
+        appeal.evidence_uri = evidence_uri;
+        appeal.bond = bond;
+        appeal.appealed_ruling = appealed_ruling;
+        appeal.status = AppealStatus::Open;
+        appeal.ruling = None;
+        appeal.panel = panel.key();
+        appeal.panel_members = panel.members.clone();
+        appeal.quorum = panel.quorum;
+        appeal.voting_ends_at = now + panel.voting_period;
+        appeal.votes_upheld = 0;
+        appeal.votes_rejected = 0;
+        appeal.filed_at = now;
+        appeal.decided_at = None;
+        appeal.ruling_notes = None;
+        appeal.bump = *ctx.bumps.get("appeal").unwrap();
+
+        ctx.accounts.challenge.appeal = Some(appeal.key());
+
+        emit!(AppealFiled {
+            appeal_id: appeal.key(),
+            challenge_id: appeal.challenge,
+            appellant,
+            bond,
         });
 
         Ok(())
     }
 
-    pub fn create_verification_report(
+    pub fn cast_appeal_vote(
+        ctx: Context<CastAppealVote>,
+        vote: ChallengeResolution,
+        ruling_notes: String,
+    ) -> Result<()> {
+        require!(ruling_notes.len() <= 500, ErrorCode::ResolutionNotesTooLong);
+
+        let appeal = &mut ctx.accounts.appeal;
+        require!(appeal.panel_members.contains(&ctx.accounts.member.key()), ErrorCode::NotPanelMember);
+        require!(appeal.status == AppealStatus::Open, ErrorCode::AppealNotOpen);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < appeal.voting_ends_at, ErrorCode::VotingClosed);
+
+        let appeal_vote = &mut ctx.accounts.appeal_vote;
+        appeal_vote.appeal = appeal.key();
+        appeal_vote.member = ctx.accounts.member.key();
+        appeal_vote.vote = vote.clone();
+        appeal_vote.voted_at = now;
+        appeal_vote.bump = *ctx.bumps.get("appeal_vote").unwrap();
+
+        match vote {
+            ChallengeResolution::Upheld => appeal.votes_upheld += 1,
+            ChallengeResolution::Rejected => appeal.votes_rejected += 1,
+        }
+
+        emit!(AppealVoteCast {
+            appeal_id: appeal.key(),
+            member: appeal_vote.member,
+            vote,
+            votes_upheld: appeal.votes_upheld,
+            votes_rejected: appeal.votes_rejected,
+        });
+
+        let ruling = if appeal.votes_upheld >= appeal.quorum {
+            ChallengeResolution::Upheld
+        } else if appeal.votes_rejected >= appeal.quorum {
+            ChallengeResolution::Rejected
+        } else {
+            return Ok(());
+        };
+
+        // The appeal ruling is final and replaces the first-instance effect on validity
+        appeal.status = AppealStatus::Decided;
+        appeal.ruling = Some(ruling.clone());
+        appeal.decided_at = Some(now);
+        appeal.ruling_notes = Some(ruling_notes);
+
+        let overturned = ruling != appeal.appealed_ruling;
+        if overturned {
+            let verification = &mut ctx.accounts.verification_result;
+            match ruling {
+                ChallengeResolution::Upheld => verification.upheld_challenges += 1,
+                ChallengeResolution::Rejected => verification.upheld_challenges -= 1,
+            }
+            verification.is_valid = verification.upheld_challenges == 0;
+        }
+
+        emit!(AppealDecided {
+            appeal_id: appeal.key(),
+            challenge_id: appeal.challenge,
+            ruling,
+            overturned,
+        });
+
+        Ok(())
+    }
+
+    pub fn settle_challenge(ctx: Context<SettleChallenge>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let challenge = &mut ctx.accounts.challenge;
+        require!(!challenge.settled, ErrorCode::ChallengeAlreadySettled);
+        let first_ruling = challenge.status.resolution().ok_or(ErrorCode::ChallengeNotResolved)?;
+        require!(
+            challenge.appeal == ctx.accounts.appeal.as_ref().map(|appeal| appeal.key()),
+            ErrorCode::AppealMismatch
+        );
+
+        // Forfeited bonds go to the other side of the case
+        let mut outcome = first_ruling.clone();
+        match &mut ctx.accounts.appeal {
+            Some(appeal) => {
+                match appeal.status {
+                    AppealStatus::Open => {
+                        require!(now >= appeal.voting_ends_at, ErrorCode::AppealPending);
+                        appeal.status = AppealStatus::Expired;
+                    }
+                    AppealStatus::Decided => outcome = appeal.ruling.clone().unwrap(),
+                    AppealStatus::Expired => {}
+                }
+
+                // A granted or lapsed appeal returns the bond; a denied one forfeits it
+                let appellant_is_challenger = appeal.appellant == challenge.challenger;
+                let appeal_bond_recipient = if appeal.status == AppealStatus::Expired || outcome != first_ruling {
+                    if appellant_is_challenger {
+                        ctx.accounts.challenger.to_account_info()
+                    } else {
+                        ctx.accounts.verifier_authority.to_account_info()
+                    }
+                } else if appellant_is_challenger {
+                    ctx.accounts.bond_recipient.to_account_info()
+                } else {
+                    ctx.accounts.challenger.to_account_info()
+                };
+                if appeal.bond > 0 {
+                    **appeal.to_account_info().try_borrow_mut_lamports()? -= appeal.bond;
+                    **appeal_bond_recipient.try_borrow_mut_lamports()? += appeal.bond;
+                }
+            }
+            None => {
+                require!(
+                    challenge.appeal_deadline.is_some_and(|deadline| now >= deadline),
+                    ErrorCode::AppealWindowOpen
+                );
+            }
+        }
+
+        // Upheld challengers get their bond back; rejected challengers forfeit it
+        let bond_recipient = match outcome {
+            ChallengeResolution::Upheld => ctx.accounts.challenger.to_account_info(),
+            ChallengeResolution::Rejected => ctx.accounts.bond_recipient.to_account_info(),
+        };
+        if challenge.bond > 0 {
+            **challenge.to_account_info().try_borrow_mut_lamports()? -= challenge.bond;
+            **bond_recipient.try_borrow_mut_lamports()? += challenge.bond;
+        }
+
+        let verification = &mut ctx.accounts.verification_result;
+        let config = &ctx.accounts.verification_config;
+        let upheld = outcome == ChallengeResolution::Upheld;
+        // Settling releases the challenge's hold on every verifier behind the result. An upheld challenge
+        // also slashes their bonded stake: part goes to the challenger, the rest is held on a slash record
+        // for compensating affected buyers.
+        // Co-verifiers are passed as (verifier, stake vault) pairs in remaining accounts, in result order
+        let co_verifiers = verification.verifiers.get(1..).unwrap_or_default();
+        require!(
+            ctx.remaining_accounts.len() == co_verifiers.len() * 2,
+            ErrorCode::VerifierAccountsRequired
+        );
+
+        let mut slashes = Vec::new();
+        ctx.accounts.verifier.close_challenge()?;
+        if upheld {
+            let lead_slashed = ctx.accounts.verifier.slash(config.slash_bps)?;
+            **ctx.accounts.stake_vault.to_account_info().try_borrow_mut_lamports()? -= lead_slashed;
+            slashes.push((ctx.accounts.verifier.key(), lead_slashed));
+        }
+        for (expected, accounts) in co_verifiers.iter().zip(ctx.remaining_accounts.chunks(2)) {
+            let (verifier_info, stake_vault_info) = (&accounts[0], &accounts[1]);
+            require_keys_eq!(verifier_info.key(), *expected, ErrorCode::VerifierMismatch);
+            let (stake_vault, _) = Pubkey::find_program_address(&[b"stake_vault", expected.as_ref()], &crate::ID);
+            require_keys_eq!(stake_vault_info.key(), stake_vault, ErrorCode::StakeVaultMismatch);
+
+            let mut verifier = Verifier::try_deserialize(&mut &verifier_info.try_borrow_data()?[..])?;
+            verifier.close_challenge()?;
+            if upheld {
+                let slashed = verifier.slash(config.slash_bps)?;
+                **stake_vault_info.try_borrow_mut_lamports()? -= slashed;
+                slashes.push((*expected, slashed));
+            }
+            verifier.try_serialize(&mut &mut verifier_info.try_borrow_mut_data()?[..])?;
+        }
+
+        let mut slashed_amount: u64 = 0;
+        if upheld {
+            let mut challenger_total: u64 = 0;
+            for (verifier_key, slashed) in slashes.iter().filter(|(_, slashed)| *slashed > 0) {
+                let challenger_amount = slashed
+                    .checked_mul(config.challenger_share_bps as u64)
+                    .ok_or(ErrorCode::MathOverflow)?
+                    / 10000;
+                slashed_amount = slashed_amount.checked_add(*slashed).ok_or(ErrorCode::MathOverflow)?;
+                challenger_total += challenger_amount;
+
+                emit!(VerifierSlashed {
+                    verifier_id: *verifier_key,
+                    verification_id: verification.key(),
+                    slashed_amount: *slashed,
+                    challenger_amount,
+                    compensation_amount: slashed - challenger_amount,
+                });
+            }
+
+            if slashed_amount > 0 {
+                let compensation_amount = slashed_amount - challenger_total;
+                let slash_record = ctx.accounts.slash_record.as_mut().ok_or(ErrorCode::SlashRecordRequired)?;
+                slash_record.challenge = challenge.key();
+                slash_record.verification = verification.key();
+                slash_record.verifier = ctx.accounts.verifier.key();
+                slash_record.project = verification.project;
+                slash_record.slashed_amount = slashed_amount;
+                slash_record.challenger_amount = challenger_total;
+                slash_record.compensation_amount = compensation_amount;
+                slash_record.compensation_distributed = 0;
+                slash_record.slashed_at = now;
+                slash_record.bump = *ctx.bumps.get("slash_record").unwrap();
+
+                **ctx.accounts.challenger.to_account_info().try_borrow_mut_lamports()? += challenger_total;
+                **slash_record.to_account_info().try_borrow_mut_lamports()? += compensation_amount;
+            }
+        }
+
+        verification.open_challenges -= 1;
+        verification.under_challenge = verification.open_challenges > 0;
+        challenge.settled = true;
+
+        // An upheld challenge claws back the verifiers' unclaimed fee holdbacks to the requester
+        let clawback = ctx.accounts.verification_request.unclaimed_holdback();
+        if outcome == ChallengeResolution::Upheld && clawback > 0 {
+            for submission in ctx.accounts.verification_request.submissions.iter_mut() {
+                submission.fee_held_back = 0;
+            }
+            transfer_from_fee_vault(
+                &ctx.accounts.verification_request,
+                &ctx.accounts.fee_vault,
+                &ctx.accounts.requester_token_account,
+                &ctx.accounts.token_program,
+                clawback,
+            )?;
+            emit!(FeeRefunded {
+                request_id: ctx.accounts.verification_request.key(),
+                requester: ctx.accounts.verification_request.requester,
+                amount: clawback,
+            });
+            close_fee_vault(
+                &ctx.accounts.verification_request,
+                &ctx.accounts.fee_vault,
+                ctx.accounts.requester.as_ref().map(|requester| requester.to_account_info()),
+                &ctx.accounts.token_program,
+            )?;
+        }
+
+        emit!(ChallengeSettled {
+            challenge_id: challenge.key(),
+            verification_id: verification.key(),
+            outcome,
+            bond_recipient: bond_recipient.key(),
+            slashed_amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn expire_challenge(ctx: Context<ExpireChallenge>) -> Result<()> {
+        let challenge = &mut ctx.accounts.challenge;
+        require!(challenge.status == ChallengeStatus::Open, ErrorCode::ChallengeNotOpen);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= challenge.voting_ends_at, ErrorCode::VotingStillOpen);
+
+        // Without a quorum the challenge lapses and the challenger is refunded
+        challenge.status = ChallengeStatus::Expired;
+        challenge.resolved_at = Some(now);
+        challenge.settled = true;
+        if challenge.bond > 0 {
+            **challenge.to_account_info().try_borrow_mut_lamports()? -= challenge.bond;
+            **ctx.accounts.challenger.to_account_info().try_borrow_mut_lamports()? += challenge.bond;
+        }
+
+        let verification = &mut ctx.accounts.verification_result;
+        verification.open_challenges -= 1;
+        verification.under_challenge = verification.open_challenges > 0;
+
+        // Co-verifiers are passed as remaining accounts, in result order
+        ctx.accounts.verifier.close_challenge()?;
+        update_co_verifiers(verification, ctx.remaining_accounts, Verifier::close_challenge)?;
+
+        emit!(ChallengeExpired {
+            challenge_id: challenge.key(),
+            verification_id: verification.key(),
+            votes_upheld: challenge.votes_upheld,
+            votes_rejected: challenge.votes_rejected,
+        });
+
+        Ok(())
+    }
+
+    pub fn update_verifier_status(
+        ctx: Context<UpdateVerifierStatus>,
+        is_active: bool,
+    ) -> Result<()> {
+        let verifier = &mut ctx.accounts.verifier;
+        let old_status = verifier.is_active;
+        verifier.is_active = is_active;
+
+        emit!(VerifierStatusUpdated {
+            verifier_id: verifier.key(),
+            old_status,
+            new_status: is_active,
+            updated_by: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn create_verification_report(
         ctx: Context<CreateVerificationReport>,
         report_uri: String,
         methodology_details: String,
@@ -219,38 +1433,299 @@ pub mod carbon_verification {
 }
 
 #[derive(Accounts)]
+pub struct InitializeVerificationConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VerificationConfig::INIT_SPACE,
+        seeds = [b"verification_config"],
+        bump
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::UnauthorizedAuthority)]
+    pub program: Program<'info, crate::program::CarbonVerification>,
+    // Only the program's upgrade authority may create the config
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::UnauthorizedAuthority)]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateVerificationConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"verification_config"],
+        bump = verification_config.bump,
+        has_one = authority @ ErrorCode::UnauthorizedAuthority
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(body_authority: Pubkey)]
+pub struct RegisterAccreditationBody<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AccreditationBody::INIT_SPACE,
+        seeds = [b"accreditation_body", body_authority.as_ref()],
+        bump
+    )]
+    pub accreditation_body: Account<'info, AccreditationBody>,
+    #[account(
+        mut,
+        seeds = [b"verification_config"],
+        bump = verification_config.bump,
+        has_one = authority @ ErrorCode::UnauthorizedAuthority
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetAccreditationBodyStatus<'info> {
+    #[account(mut)]
+    pub accreditation_body: Account<'info, AccreditationBody>,
+    #[account(
+        seeds = [b"verification_config"],
+        bump = verification_config.bump,
+        has_one = authority @ ErrorCode::UnauthorizedAuthority
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(verifier_seed: Pubkey)]
 pub struct InitializeVerifier<'info> {
     #[account(
         init,
         payer = authority,
         space = 8 + Verifier::INIT_SPACE,
-        seeds = [b"verifier", authority.key().as_ref()],
+        seeds = [b"verifier", verifier_seed.as_ref()],
         bump
     )]
     pub verifier: Account<'info, Verifier>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StakeVault::INIT_SPACE,
+        seeds = [b"stake_vault", verifier.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+    #[account(
+        mut,
+        constraint = accreditation_body.authority == accreditation_authority.key() @ ErrorCode::UnauthorizedAccreditationBody,
+        constraint = accreditation_body.is_active @ ErrorCode::AccreditationBodyInactive
+    )]
+    pub accreditation_body: Account<'info, AccreditationBody>,
+    pub accreditation_authority: Signer<'info>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct DepositStake<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAuthority)]
+    pub verifier: Account<'info, Verifier>,
+    #[account(mut, seeds = [b"stake_vault", verifier.key().as_ref()], bump = stake_vault.bump)]
+    pub stake_vault: Account<'info, StakeVault>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnbonding<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAuthority)]
+    pub verifier: Account<'info, Verifier>,
+    #[account(seeds = [b"verification_config"], bump = verification_config.bump)]
+    pub verification_config: Account<'info, VerificationConfig>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawUnbonded<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAuthority)]
+    pub verifier: Account<'info, Verifier>,
+    #[account(mut, seeds = [b"stake_vault", verifier.key().as_ref()], bump = stake_vault.bump)]
+    pub stake_vault: Account<'info, StakeVault>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DistributeCompensation<'info> {
+    #[account(mut)]
+    pub slash_record: Account<'info, SlashRecord>,
+    #[account(
+        seeds = [b"verification_config"],
+        bump = verification_config.bump,
+        has_one = authority @ ErrorCode::UnauthorizedAuthority
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+    /// CHECK: Any account may receive compensation chosen by the config authority
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetVerifierAccreditation<'info> {
+    #[account(
+        mut,
+        constraint = verifier.accreditation_body == accreditation_body.key() @ ErrorCode::UnauthorizedAccreditationBody
+    )]
+    pub verifier: Account<'info, Verifier>,
+    #[account(
+        constraint = accreditation_body.authority == accreditation_authority.key() @ ErrorCode::UnauthorizedAccreditationBody
+    )]
+    pub accreditation_body: Account<'info, AccreditationBody>,
+    pub accreditation_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeVerifierAuthority<'info> {
+    #[account(mut, has_one = authority @ ErrorCode::UnauthorizedAuthority)]
+    pub verifier: Account<'info, Verifier>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptVerifierAuthority<'info> {
+    #[account(
+        mut,
+        constraint = verifier.pending_authority == Some(new_authority.key()) @ ErrorCode::NotPendingAuthority
+    )]
+    pub verifier: Account<'info, Verifier>,
+    pub new_authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct SubmitVerificationRequest<'info> {
     #[account(
         init,
         payer = requester,
         space = 8 + VerificationRequest::INIT_SPACE,
-        seeds = [b"verification_request", project.as_ref(), requester.key().as_ref()],
+        seeds = [b"verification_request", project.key().as_ref(), requester.key().as_ref()],
         bump
     )]
     pub verification_request: Account<'info, VerificationRequest>,
-    /// CHECK: This is the project pubkey being verified
+    /// CHECK: A registry or marketplace project account; its owner and layout are checked in the handler
     pub project: UncheckedAccount<'info>,
     pub verifier: Account<'info, Verifier>,
+    #[account(address = verifier.accreditation_body @ ErrorCode::UnauthorizedAccreditationBody)]
+    pub accreditation_body: Account<'info, AccreditationBody>,
+    #[account(seeds = [b"verification_config"], bump = verification_config.bump)]
+    pub verification_config: Account<'info, VerificationConfig>,
+    pub fee_mint: Option<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = requester,
+        seeds = [b"fee_vault", verification_request.key().as_ref()],
+        bump,
+        token::mint = fee_mint,
+        token::authority = verification_request
+    )]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = requester_token_account.owner == requester.key() @ ErrorCode::TokenAccountOwnerMismatch
+    )]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
     #[account(mut)]
     pub requester: Signer<'info>,
+    pub token_program: Option<Program<'info, Token>>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct AcceptRequest<'info> {
+    #[account(mut)]
+    pub verification_request: Account<'info, VerificationRequest>,
+    #[account(constraint = verifier.authority == verifier_authority.key())]
+    pub verifier: Account<'info, Verifier>,
+    #[account(address = verifier.accreditation_body @ ErrorCode::UnauthorizedAccreditationBody)]
+    pub accreditation_body: Account<'info, AccreditationBody>,
+    #[account(seeds = [b"verification_config"], bump = verification_config.bump)]
+    pub verification_config: Account<'info, VerificationConfig>,
+    pub verifier_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RespondToRequest<'info> {
+    #[account(mut)]
+    pub verification_request: Account<'info, VerificationRequest>,
+    #[account(constraint = verifier.authority == verifier_authority.key())]
+    pub verifier: Account<'info, Verifier>,
+    #[account(mut, seeds = [b"fee_vault", verification_request.key().as_ref()], bump)]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = verifier_token_account.owner == verifier.authority @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = Some(verifier_token_account.mint) == verification_request.fee_mint @ ErrorCode::TokenAccountMintMismatch
+    )]
+    pub verifier_token_account: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = requester_token_account.owner == verification_request.requester @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = Some(requester_token_account.mint) == verification_request.fee_mint @ ErrorCode::TokenAccountMintMismatch
+    )]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Refunded the fee vault's rent when the vault is closed
+    #[account(mut, address = verification_request.requester @ ErrorCode::RequesterMismatch)]
+    pub requester: Option<UncheckedAccount<'info>>,
+    pub verifier_authority: Signer<'info>,
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRequest<'info> {
+    #[account(mut, has_one = requester)]
+    pub verification_request: Account<'info, VerificationRequest>,
+    #[account(mut, seeds = [b"fee_vault", verification_request.key().as_ref()], bump)]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = requester_token_account.owner == verification_request.requester @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = Some(requester_token_account.mint) == verification_request.fee_mint @ ErrorCode::TokenAccountMintMismatch
+    )]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub requester: Signer<'info>,
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimFeeHoldback<'info> {
+    #[account(mut)]
+    pub verification_request: Account<'info, VerificationRequest>,
+    #[account(seeds = [b"verification_result", verification_request.key().as_ref()], bump = verification_result.bump)]
+    pub verification_result: Option<Account<'info, VerificationResult>>,
+    #[account(constraint = verifier.authority == verifier_authority.key())]
+    pub verifier: Account<'info, Verifier>,
+    #[account(mut, seeds = [b"fee_vault", verification_request.key().as_ref()], bump)]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = verifier_token_account.owner == verifier.authority @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = Some(verifier_token_account.mint) == verification_request.fee_mint @ ErrorCode::TokenAccountMintMismatch
+    )]
+    pub verifier_token_account: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Refunded the fee vault's rent when the vault is closed
+    #[account(mut, address = verification_request.requester @ ErrorCode::RequesterMismatch)]
+    pub requester: Option<UncheckedAccount<'info>>,
+    pub verifier_authority: Signer<'info>,
+    pub token_program: Option<Program<'info, Token>>,
+}
+
 #[derive(Accounts)]
 pub struct ConductVerification<'info> {
     #[account(mut)]
@@ -262,11 +1737,27 @@ pub struct ConductVerification<'info> {
         seeds = [b"verification_result", verification_request.key().as_ref()],
         bump
     )]
-    pub verification_result: Account<'info, VerificationResult>,
+    pub verification_result: Option<Account<'info, VerificationResult>>,
     #[account(mut, constraint = verifier.authority == verifier_authority.key())]
     pub verifier: Account<'info, Verifier>,
+    #[account(address = verifier.accreditation_body @ ErrorCode::UnauthorizedAccreditationBody)]
+    pub accreditation_body: Account<'info, AccreditationBody>,
+    #[account(seeds = [b"verification_config"], bump = verification_config.bump)]
+    pub verification_config: Account<'info, VerificationConfig>,
+    #[account(mut, seeds = [b"fee_vault", verification_request.key().as_ref()], bump)]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = verifier_token_account.owner == verifier.authority @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = Some(verifier_token_account.mint) == verification_request.fee_mint @ ErrorCode::TokenAccountMintMismatch
+    )]
+    pub verifier_token_account: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Refunded the fee vault's rent when the vault is closed
+    #[account(mut, address = verification_request.requester @ ErrorCode::RequesterMismatch)]
+    pub requester: Option<UncheckedAccount<'info>>,
     #[account(mut)]
     pub verifier_authority: Signer<'info>,
+    pub token_program: Option<Program<'info, Token>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -282,18 +1773,213 @@ pub struct ChallengeVerification<'info> {
         bump
     )]
     pub challenge: Account<'info, VerificationChallenge>,
+    #[account(seeds = [b"verification_config"], bump = verification_config.bump)]
+    pub verification_config: Account<'info, VerificationConfig>,
+    #[account(
+        seeds = [b"arbitration_panel".as_ref(), &[PanelTier::Challenge.seed()]],
+        bump = arbitration_panel.bump
+    )]
+    pub arbitration_panel: Account<'info, ArbitrationPanel>,
+    #[account(mut, address = verification_result.verifier @ ErrorCode::VerifierMismatch)]
+    pub verifier: Account<'info, Verifier>,
     #[account(mut)]
     pub challenger: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ResolveChallenge<'info> {
+pub struct CastChallengeVote<'info> {
+    #[account(
+        mut,
+        constraint = member.key() != challenge.challenger @ ErrorCode::ConflictOfInterest
+    )]
+    pub challenge: Account<'info, VerificationChallenge>,
+    #[account(
+        init,
+        payer = member,
+        space = 8 + ChallengeVote::INIT_SPACE,
+        seeds = [b"challenge_vote", challenge.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub challenge_vote: Account<'info, ChallengeVote>,
+    #[account(
+        mut,
+        address = challenge.verification @ ErrorCode::ChallengeVerificationMismatch,
+        constraint = !verification_result.verifier_authorities.contains(&member.key()) @ ErrorCode::ConflictOfInterest
+    )]
+    pub verification_result: Account<'info, VerificationResult>,
+    #[account(
+        address = verification_result.verifier @ ErrorCode::VerifierMismatch,
+        constraint = member.key() != verifier.authority @ ErrorCode::ConflictOfInterest
+    )]
+    pub verifier: Account<'info, Verifier>,
+    #[account(seeds = [b"verification_config"], bump = verification_config.bump)]
+    pub verification_config: Account<'info, VerificationConfig>,
+    #[account(mut)]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FileAppeal<'info> {
+    #[account(mut)]
+    pub challenge: Account<'info, VerificationChallenge>,
+    #[account(
+        init,
+        payer = appellant,
+        space = 8 + ChallengeAppeal::INIT_SPACE,
+        seeds = [b"appeal", challenge.key().as_ref()],
+        bump
+    )]
+    pub appeal: Account<'info, ChallengeAppeal>,
+    #[account(address = challenge.verification @ ErrorCode::ChallengeVerificationMismatch)]
+    pub verification_result: Account<'info, VerificationResult>,
+    #[account(address = verification_result.verifier @ ErrorCode::VerifierMismatch)]
+    pub verifier: Account<'info, Verifier>,
+    #[account(seeds = [b"verification_config"], bump = verification_config.bump)]
+    pub verification_config: Account<'info, VerificationConfig>,
+    #[account(
+        seeds = [b"arbitration_panel".as_ref(), &[PanelTier::Appeal.seed()]],
+        bump = arbitration_panel.bump
+    )]
+    pub arbitration_panel: Account<'info, ArbitrationPanel>,
+    #[account(mut)]
+    pub appellant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastAppealVote<'info> {
     #[account(mut)]
+    pub appeal: Account<'info, ChallengeAppeal>,
+    #[account(
+        address = appeal.challenge @ ErrorCode::AppealMismatch,
+        constraint = member.key() != challenge.challenger @ ErrorCode::ConflictOfInterest
+    )]
     pub challenge: Account<'info, VerificationChallenge>,
+    #[account(
+        init,
+        payer = member,
+        space = 8 + AppealVote::INIT_SPACE,
+        seeds = [b"appeal_vote", appeal.key().as_ref(), member.key().as_ref()],
+        bump
+    )]
+    pub appeal_vote: Account<'info, AppealVote>,
+    #[account(
+        mut,
+        address = challenge.verification @ ErrorCode::ChallengeVerificationMismatch,
+        constraint = !verification_result.verifier_authorities.contains(&member.key()) @ ErrorCode::ConflictOfInterest
+    )]
+    pub verification_result: Account<'info, VerificationResult>,
+    #[account(
+        address = verification_result.verifier @ ErrorCode::VerifierMismatch,
+        constraint = member.key() != verifier.authority @ ErrorCode::ConflictOfInterest
+    )]
+    pub verifier: Account<'info, Verifier>,
     #[account(mut)]
+    pub member: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleChallenge<'info> {
+    #[account(mut)]
+    pub challenge: Account<'info, VerificationChallenge>,
+    #[account(mut)]
+    pub appeal: Option<Account<'info, ChallengeAppeal>>,
+    #[account(mut, address = challenge.verification @ ErrorCode::ChallengeVerificationMismatch)]
     pub verification_result: Account<'info, VerificationResult>,
-    pub resolver: Signer<'info>,
+    #[account(mut, address = verification_result.request @ ErrorCode::RequestMismatch)]
+    pub verification_request: Account<'info, VerificationRequest>,
+    #[account(mut, seeds = [b"fee_vault", verification_request.key().as_ref()], bump)]
+    pub fee_vault: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = requester_token_account.owner == verification_request.requester @ ErrorCode::TokenAccountOwnerMismatch,
+        constraint = Some(requester_token_account.mint) == verification_request.fee_mint @ ErrorCode::TokenAccountMintMismatch
+    )]
+    pub requester_token_account: Option<Account<'info, TokenAccount>>,
+    /// CHECK: Refunded the fee vault's rent when the vault is closed
+    #[account(mut, address = verification_request.requester @ ErrorCode::RequesterMismatch)]
+    pub requester: Option<UncheckedAccount<'info>>,
+    #[account(mut, address = verification_result.verifier @ ErrorCode::VerifierMismatch)]
+    pub verifier: Account<'info, Verifier>,
+    #[account(mut, seeds = [b"stake_vault", verifier.key().as_ref()], bump = stake_vault.bump)]
+    pub stake_vault: Account<'info, StakeVault>,
+    #[account(seeds = [b"verification_config"], bump = verification_config.bump)]
+    pub verification_config: Account<'info, VerificationConfig>,
+    /// CHECK: Receives the challenger's bond, slash share or forfeited appeal bonds
+    #[account(mut, address = challenge.challenger @ ErrorCode::ChallengerMismatch)]
+    pub challenger: UncheckedAccount<'info>,
+    /// CHECK: Refunded the verifier's appeal bond
+    #[account(mut, address = verifier.authority @ ErrorCode::VerifierMismatch)]
+    pub verifier_authority: UncheckedAccount<'info>,
+    /// CHECK: Receives forfeited bonds; the treasury if one is set, otherwise the verifier authority
+    #[account(
+        mut,
+        constraint = bond_recipient.key() == verification_config.treasury.unwrap_or(verifier.authority) @ ErrorCode::InvalidBondRecipient
+    )]
+    pub bond_recipient: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SlashRecord::INIT_SPACE,
+        seeds = [b"slash", challenge.key().as_ref()],
+        bump
+    )]
+    pub slash_record: Option<Account<'info, SlashRecord>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Option<Program<'info, Token>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireChallenge<'info> {
+    #[account(mut)]
+    pub challenge: Account<'info, VerificationChallenge>,
+    #[account(mut, address = challenge.verification @ ErrorCode::ChallengeVerificationMismatch)]
+    pub verification_result: Account<'info, VerificationResult>,
+    #[account(mut, address = verification_result.verifier @ ErrorCode::VerifierMismatch)]
+    pub verifier: Account<'info, Verifier>,
+    /// CHECK: Refunded the challenge bond
+    #[account(mut, address = challenge.challenger @ ErrorCode::ChallengerMismatch)]
+    pub challenger: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: PanelTier)]
+pub struct InitializeArbitrationPanel<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ArbitrationPanel::INIT_SPACE,
+        seeds = [b"arbitration_panel".as_ref(), &[tier.seed()]],
+        bump
+    )]
+    pub arbitration_panel: Account<'info, ArbitrationPanel>,
+    #[account(
+        seeds = [b"verification_config"],
+        bump = verification_config.bump,
+        has_one = authority @ ErrorCode::UnauthorizedAuthority
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateArbitrationPanel<'info> {
+    #[account(mut)]
+    pub arbitration_panel: Account<'info, ArbitrationPanel>,
+    #[account(
+        seeds = [b"verification_config"],
+        bump = verification_config.bump,
+        has_one = authority @ ErrorCode::UnauthorizedAuthority
+    )]
+    pub verification_config: Account<'info, VerificationConfig>,
+    pub authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
@@ -303,37 +1989,191 @@ pub struct UpdateVerifierStatus<'info> {
     pub authority: Signer<'info>,
 }
 
-#[derive(Accounts)]
-pub struct CreateVerificationReport<'info> {
-    pub verification_result: Account<'info, VerificationResult>,
-    #[account(
-        init,
-        payer = verifier_authority,
-        space = 8 + VerificationReport::INIT_SPACE,
-        seeds = [b"verification_report", verification_result.key().as_ref()],
-        bump
-    )]
-    pub verification_report: Account<'info, VerificationReport>,
-    #[account(constraint = verifier.authority == verifier_authority.key())]
-    pub verifier: Account<'info, Verifier>,
-    #[account(mut)]
-    pub verifier_authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
+#[derive(Accounts)]
+pub struct CreateVerificationReport<'info> {
+    pub verification_result: Account<'info, VerificationResult>,
+    #[account(
+        init,
+        payer = verifier_authority,
+        space = 8 + VerificationReport::INIT_SPACE,
+        seeds = [b"verification_report", verification_result.key().as_ref()],
+        bump
+    )]
+    pub verification_report: Account<'info, VerificationReport>,
+    #[account(constraint = verifier.authority == verifier_authority.key())]
+    pub verifier: Account<'info, Verifier>,
+    #[account(mut)]
+    pub verifier_authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VerificationConfig {
+    pub authority: Pubkey,
+    pub total_accreditation_bodies: u64,
+    // Maximum credits per request, indexed by certification level
+    pub credit_limits: [u64; 4],
+    pub min_stake: u64,
+    pub slash_bps: u16,
+    pub challenger_share_bps: u16,
+    pub unbonding_period: i64,
+    pub challenge_bond: u64,
+    pub appeal_bond: u64,
+    pub appeal_window: i64,
+    pub treasury: Option<Pubkey>,
+    pub fee_holdback_bps: u16,
+    pub fee_holdback_period: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AccreditationBody {
+    pub authority: Pubkey,
+    #[max_len(64)]
+    pub name: String,
+    pub is_active: bool,
+    pub total_verifiers_accredited: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Verifier {
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub verifier_seed: Pubkey,
+    #[max_len(64)]
+    pub verifier_name: String,
+    pub certification_level: CertificationLevel,
+    pub accreditation_body: Pubkey,
+    #[max_len(8)]
+    pub accredited_sectors: Vec<ProjectType>,
+    pub accreditation_status: AccreditationStatus,
+    pub accredited_until: i64,
+    pub is_active: bool,
+    pub total_projects_verified: u64,
+    pub total_credits_verified: u64,
+    pub stake: u64,
+    pub unbonding_amount: u64,
+    pub unbonding_available_at: Option<i64>,
+    pub total_slashed: u64,
+    pub open_challenges: u16,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Verifier {
+    pub fn require_accredited(&self, accreditation_body: &AccreditationBody) -> Result<()> {
+        require!(self.is_active, ErrorCode::VerifierInactive);
+        require!(accreditation_body.is_active, ErrorCode::AccreditationBodyInactive);
+        require!(
+            self.accreditation_status == AccreditationStatus::Active,
+            ErrorCode::VerifierNotAccredited
+        );
+        require!(
+            Clock::get()?.unix_timestamp < self.accredited_until,
+            ErrorCode::AccreditationExpired
+        );
+        Ok(())
+    }
+
+    pub fn require_in_scope(&self, config: &VerificationConfig, project_type: &ProjectType, credits: u64) -> Result<()> {
+        require!(self.accredited_sectors.contains(project_type), ErrorCode::SectorNotAccredited);
+        require!(
+            credits <= config.credit_limits[self.certification_level.clone() as usize],
+            ErrorCode::ExceedsCertificationLimit
+        );
+        Ok(())
+    }
+
+    // Slashes a share of bonded stake, taking it from active stake before unbonding stake
+    pub fn slash(&mut self, slash_bps: u16) -> Result<u64> {
+        let bonded = self
+            .stake
+            .checked_add(self.unbonding_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let slashed = bonded
+            .checked_mul(slash_bps as u64)
+            .ok_or(ErrorCode::MathOverflow)?
+            / 10000;
+        let from_stake = slashed.min(self.stake);
+        self.stake -= from_stake;
+        self.unbonding_amount -= slashed - from_stake;
+        self.total_slashed += slashed;
+        Ok(slashed)
+    }
+
+    pub fn open_challenge(&mut self) -> Result<()> {
+        self.open_challenges = self.open_challenges.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn close_challenge(&mut self) -> Result<()> {
+        self.open_challenges = self.open_challenges.checked_sub(1).ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ArbitrationPanel {
+    pub tier: PanelTier,
+    #[max_len(10)]
+    pub members: Vec<Pubkey>,
+    pub quorum: u8,
+    pub voting_period: i64,
+    pub bump: u8,
+}
+
+impl ArbitrationPanel {
+    pub fn configure(&mut self, members: Vec<Pubkey>, quorum: u8, voting_period: i64) -> Result<()> {
+        require!(!members.is_empty() && members.len() <= 10, ErrorCode::InvalidPanel);
+        // A majority quorum keeps both sides from reaching it
+        require!(
+            quorum as usize <= members.len() && quorum as usize * 2 > members.len(),
+            ErrorCode::InvalidPanel
+        );
+        require!(voting_period > 0, ErrorCode::InvalidPanel);
+
+        self.members = members;
+        self.quorum = quorum;
+        self.voting_period = voting_period;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeVote {
+    pub challenge: Pubkey,
+    pub member: Pubkey,
+    pub vote: ChallengeResolution,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakeVault {
+    pub verifier: Pubkey,
+    pub bump: u8,
 }
 
 #[account]
 #[derive(InitSpace)]
-pub struct Verifier {
-    pub authority: Pubkey,
-    #[max_len(64)]
-    pub verifier_name: String,
-    pub certification_level: CertificationLevel,
-    #[max_len(64)]
-    pub accreditation_body: String,
-    pub is_active: bool,
-    pub total_projects_verified: u64,
-    pub total_credits_verified: u64,
-    pub created_at: i64,
+pub struct SlashRecord {
+    pub challenge: Pubkey,
+    pub verification: Pubkey,
+    pub verifier: Pubkey,
+    pub project: Pubkey,
+    pub slashed_amount: u64,
+    pub challenger_amount: u64,
+    pub compensation_amount: u64,
+    pub compensation_distributed: u64,
+    pub slashed_at: i64,
     pub bump: u8,
 }
 
@@ -342,7 +2182,23 @@ pub struct Verifier {
 pub struct VerificationRequest {
     pub project: Pubkey,
     pub requester: Pubkey,
-    pub verifier: Pubkey,
+    #[max_len(5)]
+    pub verifiers: Vec<Pubkey>,
+    // Named verifiers who declined; they stay in `verifiers` but can no longer take part
+    #[max_len(5)]
+    pub declines: Vec<Pubkey>,
+    pub required_submissions: u8,
+    pub aggregation: CreditAggregation,
+    #[max_len(5)]
+    pub acceptances: Vec<VerifierAcceptance>,
+    #[max_len(5)]
+    pub submissions: Vec<VerifierSubmission>,
+    #[max_len(500)]
+    pub findings: Option<String>,
+    pub fee_mint: Option<Pubkey>,
+    pub fee_amount: u64,
+    pub fee_holdback_until: Option<i64>,
+    pub project_type: ProjectType,
     pub verification_type: VerificationType,
     #[max_len(200)]
     pub documentation_uri: String,
@@ -353,11 +2209,183 @@ pub struct VerificationRequest {
     pub bump: u8,
 }
 
+impl VerificationRequest {
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, VerificationStatus::Pending | VerificationStatus::InProgress)
+    }
+
+    // A verifier's acceptance, unless it has since declined
+    pub fn acceptance(&self, verifier: &Pubkey) -> Option<&VerifierAcceptance> {
+        if self.declines.contains(verifier) {
+            return None;
+        }
+        self.acceptances.iter().find(|acceptance| acceptance.verifier == *verifier)
+    }
+
+    // The fee is split evenly across the required submissions, the last taking any remainder
+    pub fn fee_share(&self, index: usize) -> u64 {
+        let fee = self.fee_amount as u128;
+        let required = self.required_submissions as u128;
+        let index = index as u128;
+        (fee * (index + 1) / required - fee * index / required) as u64
+    }
+
+    pub fn unclaimed_holdback(&self) -> u64 {
+        self.submissions.iter().map(|submission| submission.fee_held_back).sum()
+    }
+
+    pub fn aggregate(&self) -> (u64, u8) {
+        let mut credits: Vec<u64> = self.submissions.iter().map(|submission| submission.verified_credits).collect();
+        let mut scores: Vec<u8> = self.submissions.iter().map(|submission| submission.compliance_score).collect();
+        credits.sort_unstable();
+        scores.sort_unstable();
+        match self.aggregation {
+            CreditAggregation::Minimum => (credits[0], scores[0]),
+            // Lower median, so an even number of submissions rounds down
+            CreditAggregation::Median => {
+                let mid = (credits.len() - 1) / 2;
+                (credits[mid], scores[mid])
+            }
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VerifierAcceptance {
+    pub verifier: Pubkey,
+    pub fee_quote: Option<u64>,
+    pub deadline: Option<i64>,
+    pub accepted_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VerifierSubmission {
+    pub verifier: Pubkey,
+    pub authority: Pubkey,
+    pub verified_credits: u64,
+    pub compliance_score: u8,
+    pub submitted_at: i64,
+    pub fee_paid: u64,
+    pub fee_held_back: u64,
+}
+
+fn transfer_from_fee_vault<'info>(
+    request: &Account<'info, VerificationRequest>,
+    fee_vault: &Option<Account<'info, TokenAccount>>,
+    to: &Option<Account<'info, TokenAccount>>,
+    token_program: &Option<Program<'info, Token>>,
+    amount: u64,
+) -> Result<()> {
+    let fee_vault = fee_vault.as_ref().ok_or(ErrorCode::FeeAccountsRequired)?;
+    let to = to.as_ref().ok_or(ErrorCode::FeeAccountsRequired)?;
+    let token_program = token_program.as_ref().ok_or(ErrorCode::FeeAccountsRequired)?;
+
+    let request_seeds = &[
+        b"verification_request".as_ref(),
+        request.project.as_ref(),
+        request.requester.as_ref(),
+        &[request.bump],
+    ];
+    let signer_seeds = &[&request_seeds[..]];
+    let transfer_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        Transfer {
+            from: fee_vault.to_account_info(),
+            to: to.to_account_info(),
+            authority: request.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx.with_signer(signer_seeds), amount)
+}
+
+// Returns what is left in escrow to the requester, keeping holdbacks owed to verifiers
+fn refund_fee_escrow<'info>(
+    request: &Account<'info, VerificationRequest>,
+    fee_vault: &Option<Account<'info, TokenAccount>>,
+    requester_token_account: &Option<Account<'info, TokenAccount>>,
+    requester: Option<AccountInfo<'info>>,
+    token_program: &Option<Program<'info, Token>>,
+    already_paid: u64,
+) -> Result<()> {
+    if request.fee_amount == 0 {
+        return Ok(());
+    }
+    let vault_balance = fee_vault.as_ref().ok_or(ErrorCode::FeeAccountsRequired)?.amount;
+    let refund = vault_balance
+        .checked_sub(already_paid)
+        .and_then(|balance| balance.checked_sub(request.unclaimed_holdback()))
+        .ok_or(ErrorCode::MathOverflow)?;
+    if refund > 0 {
+        transfer_from_fee_vault(request, fee_vault, requester_token_account, token_program, refund)?;
+        emit!(FeeRefunded {
+            request_id: request.key(),
+            requester: request.requester,
+            amount: refund,
+        });
+    }
+    if request.unclaimed_holdback() == 0 {
+        close_fee_vault(request, fee_vault, requester, token_program)?;
+    }
+    Ok(())
+}
+
+// Applies `update` to the co-verifiers behind a result, passed as remaining accounts in result order
+fn update_co_verifiers(
+    verification: &VerificationResult,
+    accounts: &[AccountInfo],
+    update: fn(&mut Verifier) -> Result<()>,
+) -> Result<()> {
+    let co_verifiers = verification.verifiers.get(1..).unwrap_or_default();
+    require!(accounts.len() == co_verifiers.len(), ErrorCode::VerifierAccountsRequired);
+    for (expected, verifier_info) in co_verifiers.iter().zip(accounts) {
+        require_keys_eq!(verifier_info.key(), *expected, ErrorCode::VerifierMismatch);
+        let mut verifier = Verifier::try_deserialize(&mut &verifier_info.try_borrow_data()?[..])?;
+        update(&mut verifier)?;
+        verifier.try_serialize(&mut &mut verifier_info.try_borrow_mut_data()?[..])?;
+    }
+    Ok(())
+}
+
+// Closes an emptied fee vault, returning its rent to the requester
+fn close_fee_vault<'info>(
+    request: &Account<'info, VerificationRequest>,
+    fee_vault: &Option<Account<'info, TokenAccount>>,
+    requester: Option<AccountInfo<'info>>,
+    token_program: &Option<Program<'info, Token>>,
+) -> Result<()> {
+    let fee_vault = fee_vault.as_ref().ok_or(ErrorCode::FeeAccountsRequired)?;
+    let requester = requester.ok_or(ErrorCode::FeeAccountsRequired)?;
+    let token_program = token_program.as_ref().ok_or(ErrorCode::FeeAccountsRequired)?;
+
+    let request_seeds = &[
+        b"verification_request".as_ref(),
+        request.project.as_ref(),
+        request.requester.as_ref(),
+        &[request.bump],
+    ];
+    let signer_seeds = &[&request_seeds[..]];
+    let close_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: fee_vault.to_account_info(),
+            destination: requester,
+            authority: request.to_account_info(),
+        },
+    );
+    token::close_account(close_ctx.with_signer(signer_seeds))
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct VerificationResult {
     pub request: Pubkey,
+    // The first verifier to submit answers challenges; all contributing verifiers are listed
     pub verifier: Pubkey,
+    #[max_len(5)]
+    pub verifiers: Vec<Pubkey>,
+    // Authorities of the contributing verifiers when they submitted, kept off the arbitration panels
+    #[max_len(5)]
+    pub verifier_authorities: Vec<Pubkey>,
     pub project: Pubkey,
     pub verified_credits: u64,
     #[max_len(500)]
@@ -366,6 +2394,10 @@ pub struct VerificationResult {
     pub methodology_used: VerificationType,
     pub verified_at: i64,
     pub is_valid: bool,
+    pub under_challenge: bool,
+    // Challenges not yet settled, and challenges whose ruling currently stands as upheld
+    pub open_challenges: u16,
+    pub upheld_challenges: u16,
     pub bump: u8,
 }
 
@@ -378,11 +2410,61 @@ pub struct VerificationChallenge {
     pub challenge_reason: String,
     #[max_len(200)]
     pub evidence_uri: String,
+    pub bond: u64,
     pub status: ChallengeStatus,
+    pub panel: Pubkey,
+    // Panel membership and quorum when the challenge was filed; later panel updates don't apply
+    #[max_len(10)]
+    pub panel_members: Vec<Pubkey>,
+    pub quorum: u8,
+    pub voting_ends_at: i64,
+    pub votes_upheld: u8,
+    pub votes_rejected: u8,
     pub submitted_at: i64,
     pub resolved_at: Option<i64>,
     #[max_len(500)]
     pub resolution_notes: Option<String>,
+    pub appeal_deadline: Option<i64>,
+    pub appeal: Option<Pubkey>,
+    pub settled: bool,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeAppeal {
+    pub challenge: Pubkey,
+    pub appellant: Pubkey,
+    #[max_len(500)]
+    pub appeal_reason: String,
+    #[max_len(200)]
+    pub evidence_uri: String,
+    pub bond: u64,
+    pub appealed_ruling: ChallengeResolution,
+    pub status: AppealStatus,
+    pub ruling: Option<ChallengeResolution>,
+    pub panel: Pubkey,
+    // Appeal panel membership and quorum when the appeal was filed
+    #[max_len(10)]
+    pub panel_members: Vec<Pubkey>,
+    pub quorum: u8,
+    pub voting_ends_at: i64,
+    pub votes_upheld: u8,
+    pub votes_rejected: u8,
+    pub filed_at: i64,
+    pub decided_at: Option<i64>,
+    #[max_len(500)]
+    pub ruling_notes: Option<String>,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AppealVote {
+    pub appeal: Pubkey,
+    pub member: Pubkey,
+    pub vote: ChallengeResolution,
+    pub voted_at: i64,
     pub bump: u8,
 }
 
@@ -401,6 +2483,50 @@ pub struct VerificationReport {
     pub bump: u8,
 }
 
+// Leading fields of the carbon-registry `ProjectRegistry` account, up to its project type
+#[derive(AnchorDeserialize)]
+struct RegistryProjectHeader {
+    _project_id: String,
+    _vintage_year: u16,
+    _methodology: Pubkey,
+    _country_code: [u8; 2],
+    _subdivision_code: [u8; 3],
+    _project_developer: Pubkey,
+    project_type: ProjectType,
+}
+
+// Leading fields of the carbon-marketplace `CarbonProject` account, up to its project type
+#[derive(AnchorDeserialize)]
+struct MarketplaceProjectHeader {
+    _project_id: String,
+    _project_name: String,
+    project_type: ProjectType,
+}
+
+fn account_discriminator(name: &str) -> [u8; 8] {
+    let hash = anchor_lang::solana_program::hash::hash(format!("account:{}", name).as_bytes());
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
+    discriminator
+}
+
+// Reads the sector of a registry or marketplace project; both programs order `ProjectType` the same way
+fn project_type_of(project: &AccountInfo) -> Result<ProjectType> {
+    let data = project.try_borrow_data()?;
+    require!(data.len() > 8, ErrorCode::UnsupportedProject);
+    let (discriminator, mut fields) = data.split_at(8);
+    let project_type = if *project.owner == CARBON_REGISTRY_ID
+        && discriminator == account_discriminator("ProjectRegistry")
+    {
+        RegistryProjectHeader::deserialize(&mut fields).map(|header| header.project_type)
+    } else if *project.owner == CARBON_MARKETPLACE_ID && discriminator == account_discriminator("CarbonProject") {
+        MarketplaceProjectHeader::deserialize(&mut fields).map(|header| header.project_type)
+    } else {
+        return err!(ErrorCode::UnsupportedProject);
+    };
+    project_type.map_err(|_| error!(ErrorCode::UnsupportedProject))
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum CertificationLevel {
     Basic,
@@ -409,6 +2535,25 @@ pub enum CertificationLevel {
     Expert,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum AccreditationStatus {
+    Active,
+    Suspended,
+    Revoked,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProjectType {
+    Forestry,
+    RenewableEnergy,
+    EnergyEfficiency,
+    Methane,
+    Transportation,
+    Agriculture,
+    WasteManagement,
+    CarbonCapture,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum VerificationType {
     Initial,
@@ -417,12 +2562,21 @@ pub enum VerificationType {
     Surveillance,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum CreditAggregation {
+    Minimum,
+    Median,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum VerificationStatus {
     Pending,
     InProgress,
     Completed,
     Rejected,
+    Declined,
+    Withdrawn,
+    Expired,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
@@ -430,6 +2584,36 @@ pub enum ChallengeStatus {
     Open,
     Upheld,
     Rejected,
+    Expired,
+}
+
+impl ChallengeStatus {
+    pub fn resolution(&self) -> Option<ChallengeResolution> {
+        match self {
+            ChallengeStatus::Upheld => Some(ChallengeResolution::Upheld),
+            ChallengeStatus::Rejected => Some(ChallengeResolution::Rejected),
+            ChallengeStatus::Open | ChallengeStatus::Expired => None,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum AppealStatus {
+    Open,
+    Decided,
+    Expired,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum PanelTier {
+    Challenge,
+    Appeal,
+}
+
+impl PanelTier {
+    pub fn seed(&self) -> u8 {
+        self.clone() as u8
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
@@ -438,12 +2622,114 @@ pub enum ChallengeResolution {
     Rejected,
 }
 
+impl ChallengeResolution {
+    pub fn status(&self) -> ChallengeStatus {
+        match self {
+            ChallengeResolution::Upheld => ChallengeStatus::Upheld,
+            ChallengeResolution::Rejected => ChallengeStatus::Rejected,
+        }
+    }
+}
+
 #[event]
 pub struct VerifierInitialized {
     pub verifier_id: Pubkey,
     pub authority: Pubkey,
     pub verifier_name: String,
     pub certification_level: CertificationLevel,
+    pub accreditation_body: Pubkey,
+}
+
+#[event]
+pub struct CertificationLimitsUpdated {
+    pub credit_limits: [u64; 4],
+}
+
+#[event]
+pub struct StakingParamsUpdated {
+    pub min_stake: u64,
+    pub slash_bps: u16,
+    pub challenger_share_bps: u16,
+    pub unbonding_period: i64,
+}
+
+#[event]
+pub struct ChallengeParamsUpdated {
+    pub challenge_bond: u64,
+    pub appeal_bond: u64,
+    pub appeal_window: i64,
+    pub treasury: Option<Pubkey>,
+}
+
+#[event]
+pub struct StakeDeposited {
+    pub verifier_id: Pubkey,
+    pub amount: u64,
+    pub total_stake: u64,
+}
+
+#[event]
+pub struct UnbondingRequested {
+    pub verifier_id: Pubkey,
+    pub amount: u64,
+    pub available_at: i64,
+}
+
+#[event]
+pub struct StakeWithdrawn {
+    pub verifier_id: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct VerifierSlashed {
+    pub verifier_id: Pubkey,
+    pub verification_id: Pubkey,
+    pub slashed_amount: u64,
+    pub challenger_amount: u64,
+    pub compensation_amount: u64,
+}
+
+#[event]
+pub struct CompensationDistributed {
+    pub slash_record_id: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AccreditationBodyRegistered {
+    pub accreditation_body_id: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+}
+
+#[event]
+pub struct AccreditationBodyStatusUpdated {
+    pub accreditation_body_id: Pubkey,
+    pub is_active: bool,
+}
+
+#[event]
+pub struct VerifierAccreditationUpdated {
+    pub verifier_id: Pubkey,
+    pub accreditation_body: Pubkey,
+    pub status: AccreditationStatus,
+    pub accredited_until: i64,
+}
+
+#[event]
+pub struct VerifierAuthorityProposed {
+    pub verifier_id: Pubkey,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct VerifierAuthorityAccepted {
+    pub verifier_id: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
 }
 
 #[event]
@@ -451,8 +2737,81 @@ pub struct VerificationRequestSubmitted {
     pub request_id: Pubkey,
     pub project: Pubkey,
     pub requester: Pubkey,
-    pub verifier: Pubkey,
+    pub verifiers: Vec<Pubkey>,
+    pub required_submissions: u8,
     pub verification_type: VerificationType,
+    pub fee_mint: Option<Pubkey>,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct FeeReleased {
+    pub request_id: Pubkey,
+    pub verifier: Pubkey,
+    pub amount: u64,
+    pub held_back: u64,
+}
+
+#[event]
+pub struct FeeRefunded {
+    pub request_id: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeeParamsUpdated {
+    pub fee_holdback_bps: u16,
+    pub fee_holdback_period: i64,
+}
+
+#[event]
+pub struct RequestExpired {
+    pub request_id: Pubkey,
+    pub project: Pubkey,
+    pub requester: Pubkey,
+}
+
+#[event]
+pub struct RequestAccepted {
+    pub request_id: Pubkey,
+    pub verifier: Pubkey,
+    pub fee_quote: Option<u64>,
+    pub deadline: Option<i64>,
+}
+
+#[event]
+pub struct RequestDeclined {
+    pub request_id: Pubkey,
+    pub verifier: Pubkey,
+    pub reason: String,
+    pub status: VerificationStatus,
+}
+
+#[event]
+pub struct VerificationRejected {
+    pub request_id: Pubkey,
+    pub project: Pubkey,
+    pub verifier: Pubkey,
+    pub findings: String,
+}
+
+#[event]
+pub struct RequestWithdrawn {
+    pub request_id: Pubkey,
+    pub project: Pubkey,
+    pub requester: Pubkey,
+}
+
+#[event]
+pub struct VerificationSubmitted {
+    pub request_id: Pubkey,
+    pub verifier: Pubkey,
+    pub verified_credits: u64,
+    pub compliance_score: u8,
+    pub verification_notes: String,
+    pub submissions: u8,
+    pub required_submissions: u8,
 }
 
 #[event]
@@ -460,7 +2819,7 @@ pub struct VerificationCompleted {
     pub verification_id: Pubkey,
     pub request_id: Pubkey,
     pub project: Pubkey,
-    pub verifier: Pubkey,
+    pub verifiers: Vec<Pubkey>,
     pub verified_credits: u64,
     pub compliance_score: u8,
 }
@@ -471,6 +2830,7 @@ pub struct VerificationChallenged {
     pub verification_id: Pubkey,
     pub challenger: Pubkey,
     pub reason: String,
+    pub bond: u64,
 }
 
 #[event]
@@ -479,6 +2839,67 @@ pub struct ChallengeResolved {
     pub verification_id: Pubkey,
     pub resolution: ChallengeResolution,
     pub resolver: Pubkey,
+    pub appeal_deadline: i64,
+}
+
+#[event]
+pub struct AppealFiled {
+    pub appeal_id: Pubkey,
+    pub challenge_id: Pubkey,
+    pub appellant: Pubkey,
+    pub bond: u64,
+}
+
+#[event]
+pub struct AppealVoteCast {
+    pub appeal_id: Pubkey,
+    pub member: Pubkey,
+    pub vote: ChallengeResolution,
+    pub votes_upheld: u8,
+    pub votes_rejected: u8,
+}
+
+#[event]
+pub struct AppealDecided {
+    pub appeal_id: Pubkey,
+    pub challenge_id: Pubkey,
+    pub ruling: ChallengeResolution,
+    pub overturned: bool,
+}
+
+#[event]
+pub struct ChallengeSettled {
+    pub challenge_id: Pubkey,
+    pub verification_id: Pubkey,
+    pub outcome: ChallengeResolution,
+    pub bond_recipient: Pubkey,
+    pub slashed_amount: u64,
+}
+
+#[event]
+pub struct ArbitrationPanelUpdated {
+    pub panel_id: Pubkey,
+    pub tier: PanelTier,
+    pub members: Vec<Pubkey>,
+    pub quorum: u8,
+    pub voting_period: i64,
+}
+
+#[event]
+pub struct ChallengeVoteCast {
+    pub challenge_id: Pubkey,
+    pub member: Pubkey,
+    pub vote: ChallengeResolution,
+    pub votes_upheld: u8,
+    pub votes_rejected: u8,
+}
+
+#[event]
+pub struct ChallengeExpired {
+    pub challenge_id: Pubkey,
+    pub verification_id: Pubkey,
+    pub votes_upheld: u8,
+    pub votes_rejected: u8,
 }
 
 #[event]
@@ -526,4 +2947,142 @@ pub enum ErrorCode {
     MethodologyDetailsTooLong,
     #[msg("Sampling approach too long")]
     SamplingApproachTooLong,
+    #[msg("Signer is not the authority")]
+    UnauthorizedAuthority,
+    #[msg("Signer is not the pending verifier authority")]
+    NotPendingAuthority,
+    #[msg("Signer is not the verifier's accreditation body")]
+    UnauthorizedAccreditationBody,
+    #[msg("Accreditation body is not active")]
+    AccreditationBodyInactive,
+    #[msg("Accredited sectors must list between one and eight sectors")]
+    InvalidAccreditedSectors,
+    #[msg("Accreditation must end in the future")]
+    InvalidAccreditationPeriod,
+    #[msg("Verifier accreditation has been revoked")]
+    AccreditationRevoked,
+    #[msg("Verifier is not active")]
+    VerifierInactive,
+    #[msg("Verifier accreditation is suspended or revoked")]
+    VerifierNotAccredited,
+    #[msg("Verifier accreditation has expired")]
+    AccreditationExpired,
+    #[msg("Credit limits must not decrease with certification level")]
+    InvalidCertificationLimits,
+    #[msg("Verifier is not accredited for this project's sector")]
+    SectorNotAccredited,
+    #[msg("Credit volume exceeds the verifier's certification level limit")]
+    ExceedsCertificationLimit,
+    #[msg("Slash and challenger shares cannot exceed 100% and unbonding period cannot be negative")]
+    InvalidStakingParams,
+    #[msg("Invalid stake amount")]
+    InvalidStakeAmount,
+    #[msg("Verifier stake is below the required minimum")]
+    InsufficientStake,
+    #[msg("No stake is unbonding")]
+    NothingUnbonding,
+    #[msg("Unbonding period has not elapsed")]
+    UnbondingPeriodActive,
+    #[msg("A slash record is required to slash the verifier")]
+    SlashRecordRequired,
+    #[msg("Challenge does not belong to this verification")]
+    ChallengeVerificationMismatch,
+    #[msg("Verifier did not perform this verification")]
+    VerifierMismatch,
+    #[msg("Account is not the challenger")]
+    ChallengerMismatch,
+    #[msg("Verification is not valid")]
+    VerificationNotValid,
+    #[msg("Forfeited bonds go to the treasury, or the verifier authority when none is set")]
+    InvalidBondRecipient,
+    #[msg("Panel needs 1-10 members, a majority quorum and a positive voting period")]
+    InvalidPanel,
+    #[msg("Signer is not a member of the arbitration panel")]
+    NotPanelMember,
+    #[msg("The verifier and challenger cannot vote on their own case")]
+    ConflictOfInterest,
+    #[msg("Voting period has ended")]
+    VotingClosed,
+    #[msg("Voting period has not ended")]
+    VotingStillOpen,
+    #[msg("Appeal bond must exceed the challenge bond and the appeal window cannot be negative")]
+    InvalidChallengeParams,
+    #[msg("Challenge has not been resolved")]
+    ChallengeNotResolved,
+    #[msg("Appeal window has closed")]
+    AppealWindowClosed,
+    #[msg("Appeal window is still open")]
+    AppealWindowOpen,
+    #[msg("Only the challenger or the verifier can appeal")]
+    NotChallengeParty,
+    #[msg("Appeal is not open")]
+    AppealNotOpen,
+    #[msg("Appeal is still being decided")]
+    AppealPending,
+    #[msg("Appeal does not match the challenge")]
+    AppealMismatch,
+    #[msg("Challenge has already been settled")]
+    ChallengeAlreadySettled,
+    #[msg("A request can name at most 5 verifiers")]
+    TooManyVerifiers,
+    #[msg("Verifier is named more than once")]
+    DuplicateVerifier,
+    #[msg("Required submissions must be between 1 and the number of verifiers")]
+    InvalidSubmissionThreshold,
+    #[msg("Verifier is not named on this request")]
+    NotRequestVerifier,
+    #[msg("Verifier has already submitted for this request")]
+    AlreadySubmitted,
+    #[msg("Verification result is only created once enough verifiers have submitted")]
+    ConsensusNotReached,
+    #[msg("Verification result account is required to complete the verification")]
+    VerificationResultRequired,
+    #[msg("Request is no longer open")]
+    RequestNotOpen,
+    #[msg("Verifier has not accepted this request")]
+    RequestNotAccepted,
+    #[msg("Verifier has already accepted this request")]
+    AlreadyAccepted,
+    #[msg("Deadline must be in the future")]
+    InvalidDeadline,
+    #[msg("Findings too long")]
+    FindingsTooLong,
+    #[msg("Fee holdback must be at most 10000 basis points and the holdback period cannot be negative")]
+    InvalidFeeParams,
+    #[msg("Fee vault, token account and token program are required when a fee is escrowed")]
+    FeeAccountsRequired,
+    #[msg("Token account owner mismatch")]
+    TokenAccountOwnerMismatch,
+    #[msg("Token account mint does not match the fee mint")]
+    TokenAccountMintMismatch,
+    #[msg("No accepted verifier has missed its deadline")]
+    DeadlineNotPassed,
+    #[msg("Fee holdback cannot be claimed yet")]
+    FeeHoldbackActive,
+    #[msg("No fee holdback to claim")]
+    NoFeeHoldback,
+    #[msg("Verification request does not match the verification result")]
+    RequestMismatch,
+    #[msg("Project account does not match the project key")]
+    ProjectMismatch,
+    #[msg("Project must be a carbon-registry or carbon-marketplace project account")]
+    UnsupportedProject,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Appeal panel shares members with the panel that ruled on the challenge")]
+    AppealPanelOverlap,
+    #[msg("Another verifier under the same authority has already submitted")]
+    DuplicateVerifierAuthority,
+    #[msg("Each co-verifier and its stake vault must be passed to slash them")]
+    VerifierAccountsRequired,
+    #[msg("Stake vault does not belong to the verifier")]
+    StakeVaultMismatch,
+    #[msg("Verifier has declined the request")]
+    VerifierDeclined,
+    #[msg("Verifier's accepted deadline has passed")]
+    DeadlinePassed,
+    #[msg("Account is not the request's requester")]
+    RequesterMismatch,
+    #[msg("Stake cannot be withdrawn while challenges against the verifier are unsettled")]
+    ChallengesPending,
 }
\ No newline at end of file
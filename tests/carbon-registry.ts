@@ -0,0 +1,1920 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, AnchorError, BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  approve,
+  createAccount,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import { CarbonRegistry } from "../target/types/carbon_registry";
+import { CarbonVerification } from "../target/types/carbon_verification";
+
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const YEAR = 365 * 24 * 60 * 60;
+const now = () => Math.floor(Date.now() / 1000);
+
+describe("carbon-registry account constraints", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.CarbonRegistry as Program<CarbonRegistry>;
+  const verificationProgram = anchor.workspace.CarbonVerification as Program<CarbonVerification>;
+  const connection = provider.connection;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+
+  const pda = (seeds: (Buffer | Uint8Array)[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+  const registryPda = (registrySeed: PublicKey) => pda([Buffer.from("registry"), registrySeed.toBuffer()]);
+  const projectPda = (registry: PublicKey, projectId: string) =>
+    pda([Buffer.from("project_registry"), registry.toBuffer(), Buffer.from(projectId)]);
+  const creditMintPda = (project: PublicKey) => pda([Buffer.from("credit_mint"), project.toBuffer()]);
+  const issuancePda = (project: PublicKey, authority: PublicKey) =>
+    pda([Buffer.from("credit_issuance"), project.toBuffer(), authority.toBuffer()]);
+  const transferRecordPda = (project: PublicKey, owner: PublicKey) =>
+    pda([Buffer.from("transfer_record"), project.toBuffer(), owner.toBuffer()]);
+  const retirementPda = (project: PublicKey, owner: PublicKey) =>
+    pda([Buffer.from("credit_retirement"), project.toBuffer(), owner.toBuffer()]);
+  const batchPda = (project: PublicKey, batchId: string) =>
+    pda([Buffer.from("credit_batch"), project.toBuffer(), Buffer.from(batchId)]);
+  const metadataPda = (project: PublicKey, authority: PublicKey) =>
+    pda([Buffer.from("project_metadata"), project.toBuffer(), authority.toBuffer()]);
+
+  const ROLE_SEEDS = { admin: 0, issuer: 1, projectApprover: 2, metadataEditor: 3, auditor: 4 };
+  type RoleName = keyof typeof ROLE_SEEDS;
+  const rolePda = (registry: PublicKey, holder: PublicKey, role: RoleName) =>
+    pda([Buffer.from("role"), registry.toBuffer(), holder.toBuffer(), Buffer.from([ROLE_SEEDS[role]])]);
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+      return;
+    }
+    expect.fail(`expected ${code}`);
+  }
+
+  async function fundedKeypair(): Promise<Keypair> {
+    const keypair = Keypair.generate();
+    const sig = await connection.requestAirdrop(keypair.publicKey, 2 * LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(sig);
+    return keypair;
+  }
+
+  async function initializeRegistry(authority: Keypair, name: string): Promise<PublicKey> {
+    const registrySeed = Keypair.generate().publicKey;
+    const registry = registryPda(registrySeed);
+    await program.methods
+      .initializeRegistry(registrySeed, name, "https://registry.example.org")
+      .accounts({
+        registry,
+        adminRole: rolePda(registry, authority.publicKey, "admin"),
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+    return registry;
+  }
+
+  async function grantRole(registry: PublicKey, admin: Keypair, holder: PublicKey, role: RoleName) {
+    await program.methods
+      .grantRole(holder, { [role]: {} } as any)
+      .accounts({
+        roleAssignment: rolePda(registry, holder, role),
+        registry,
+        adminRole: rolePda(registry, admin.publicKey, "admin"),
+        admin: admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+  }
+
+  const statusHistoryPda = (project: PublicKey, sequence: number) =>
+    pda([Buffer.from("status_history"), project.toBuffer(), new BN(sequence).toArrayLike(Buffer, "le", 8)]);
+  const methodologyPda = (registry: PublicKey, methodologyId: string, version: string) =>
+    pda([Buffer.from("methodology"), registry.toBuffer(), Buffer.from(methodologyId), Buffer.from(version)]);
+  const countryStatsPda = (registry: PublicKey, countryCode: string | number[]) =>
+    pda([Buffer.from("country_stats"), registry.toBuffer(), Buffer.from(countryCode as any)]);
+  const applicationPda = (registry: PublicKey, projectId: string) =>
+    pda([Buffer.from("project_application"), registry.toBuffer(), Buffer.from(projectId)]);
+
+  async function addMethodology(
+    registry: PublicKey,
+    admin: Keypair,
+    methodologyId: string,
+    version: string,
+    sector: object
+  ): Promise<PublicKey> {
+    const methodology = methodologyPda(registry, methodologyId, version);
+    await program.methods
+      .addMethodology(methodologyId, version, { cdm: {} } as any, sector as any, 7, 10, 2)
+      .accounts({
+        methodology,
+        registry,
+        adminRole: rolePda(registry, admin.publicKey, "admin"),
+        authority: admin.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+    return methodology;
+  }
+
+  async function submitApplication(
+    registry: PublicKey,
+    developer: Keypair,
+    projectId: string,
+    methodology = methodologyPda(registry, "ACM0002", "19.0"),
+    creditingPeriodStart = now() - YEAR,
+    country = "KE",
+    subdivision: string | null = null,
+    projectType: object = { renewableEnergy: {} },
+    vintageYear = new Date(creditingPeriodStart * 1000).getUTCFullYear()
+  ): Promise<PublicKey> {
+    const projectApplication = applicationPda(registry, projectId);
+    await program.methods
+      .submitProjectApplication(
+        projectId,
+        vintageYear,
+        country,
+        subdivision,
+        projectType as any,
+        ["https://docs.example.org/pdd.pdf"],
+        new BN(creditingPeriodStart),
+        7
+      )
+      .accounts({
+        projectApplication,
+        methodology,
+        registry,
+        developer: developer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([developer])
+      .rpc();
+    return projectApplication;
+  }
+
+  async function registerProject(
+    registry: PublicKey,
+    authority: Keypair,
+    projectId: string,
+    developer: Keypair,
+    country = "KE",
+    subdivision: string | null = null,
+    methodology = methodologyPda(registry, "ACM0002", "19.0"),
+    projectType: object = { renewableEnergy: {} }
+  ): Promise<PublicKey> {
+    const projectApplication = await submitApplication(
+      registry,
+      developer,
+      projectId,
+      methodology,
+      now() - YEAR,
+      country,
+      subdivision,
+      projectType
+    );
+    const { countryCode } = await program.account.projectApplication.fetch(projectApplication);
+    const projectRegistry = projectPda(registry, projectId);
+    await program.methods
+      .approveProjectApplication()
+      .accounts({
+        projectApplication,
+        methodology,
+        projectRegistry,
+        creditMint: creditMintPda(projectRegistry),
+        registry,
+        countryStats: countryStatsPda(registry, countryCode),
+        developer: developer.publicKey,
+        approverRole: rolePda(registry, authority.publicKey, "projectApprover"),
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([authority])
+      .rpc();
+    return projectRegistry;
+  }
+
+  let authorityA: Keypair;
+  let authorityB: Keypair;
+  let developer: Keypair;
+  let holder: Keypair;
+  let outsider: Keypair;
+  let registryA: PublicKey;
+  let registryB: PublicKey;
+  let projectA: PublicKey;
+  let projectB: PublicKey;
+  let mintA: PublicKey;
+  let mintB: PublicKey;
+  let holderAccountA: PublicKey;
+  let outsiderAccountA: PublicKey;
+  let outsiderAccountB: PublicKey;
+
+  before(async () => {
+    authorityA = payer;
+    authorityB = await fundedKeypair();
+    developer = await fundedKeypair();
+    holder = await fundedKeypair();
+    outsider = await fundedKeypair();
+
+    registryA = await initializeRegistry(authorityA, "Registry A");
+    registryB = await initializeRegistry(authorityB, "Registry B");
+    for (const role of ["issuer", "projectApprover"] as RoleName[]) {
+      await grantRole(registryA, authorityA, authorityA.publicKey, role);
+      await grantRole(registryB, authorityB, authorityB.publicKey, role);
+    }
+    await addMethodology(registryA, authorityA, "ACM0002", "19.0", { renewableEnergy: {} });
+    await addMethodology(registryB, authorityB, "ACM0002", "19.0", { renewableEnergy: {} });
+    for (const [registry, authority] of [
+      [registryA, authorityA],
+      [registryB, authorityB],
+    ] as [PublicKey, Keypair][]) {
+      await program.methods
+        .initializeCountryStats(Array.from(Buffer.from("KE")))
+        .accounts({
+          countryStats: countryStatsPda(registry, "KE"),
+          registry,
+          adminRole: rolePda(registry, authority.publicKey, "admin"),
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+    projectA = await registerProject(registryA, authorityA, "PRJ-A", developer);
+    projectB = await registerProject(registryB, authorityB, "PRJ-B", developer);
+    mintA = creditMintPda(projectA);
+    mintB = creditMintPda(projectB);
+
+    holderAccountA = (await getOrCreateAssociatedTokenAccount(connection, payer, mintA, holder.publicKey)).address;
+    outsiderAccountA = (await getOrCreateAssociatedTokenAccount(connection, payer, mintA, outsider.publicKey)).address;
+    outsiderAccountB = (await getOrCreateAssociatedTokenAccount(connection, payer, mintB, outsider.publicKey)).address;
+  });
+
+  describe("project applications", () => {
+    it("binds the approved project to the signing developer", async () => {
+      const state = await program.account.projectRegistry.fetch(projectA);
+      expect(state.projectDeveloper.toBase58()).to.equal(developer.publicKey.toBase58());
+      expect(await connection.getAccountInfo(applicationPda(registryA, "PRJ-A"))).to.be.null;
+    });
+
+    it("rejects approval by a signer without the project approver role", async () => {
+      const projectApplication = await submitApplication(registryA, developer, "PRJ-OUT");
+      const projectRegistry = projectPda(registryA, "PRJ-OUT");
+      await expectError(
+        program.methods
+          .approveProjectApplication()
+          .accounts({
+            projectApplication,
+            methodology: methodologyPda(registryA, "ACM0002", "19.0"),
+            projectRegistry,
+            creditMint: creditMintPda(projectRegistry),
+            registry: registryA,
+            countryStats: countryStatsPda(registryA, "KE"),
+            developer: developer.publicKey,
+            approverRole: rolePda(registryA, outsider.publicKey, "projectApprover"),
+            authority: outsider.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([outsider])
+          .rpc(),
+        "AccountNotInitialized"
+      );
+    });
+
+    it("forfeits the deposit when an application is rejected and lets an admin withdraw it", async () => {
+      await program.methods
+        .setApplicationDeposit(new BN(LAMPORTS_PER_SOL / 10))
+        .accounts({
+          registry: registryA,
+          adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+          authority: authorityA.publicKey,
+        })
+        .rpc();
+      const projectApplication = await submitApplication(registryA, developer, "PRJ-REJ");
+      const registryBalance = await connection.getBalance(registryA);
+
+      const rejectAccounts = (developerKey: PublicKey) => ({
+        projectApplication,
+        registry: registryA,
+        developer: developerKey,
+        approverRole: rolePda(registryA, authorityA.publicKey, "projectApprover"),
+        authority: authorityA.publicKey,
+      });
+      await expectError(
+        program.methods.rejectProjectApplication("redirected refund").accounts(rejectAccounts(outsider.publicKey)).rpc(),
+        "ApplicationDeveloperMismatch"
+      );
+      await program.methods
+        .rejectProjectApplication("incomplete documentation")
+        .accounts(rejectAccounts(developer.publicKey))
+        .rpc();
+
+      expect(await connection.getBalance(registryA)).to.equal(registryBalance + LAMPORTS_PER_SOL / 10);
+      expect(await connection.getAccountInfo(projectApplication)).to.be.null;
+      const { forfeitedDeposits } = await program.account.registry.fetch(registryA);
+      expect(forfeitedDeposits.toNumber()).to.equal(LAMPORTS_PER_SOL / 10);
+
+      const treasury = Keypair.generate().publicKey;
+      const withdrawAccounts = (signer: PublicKey) => ({
+        registry: registryA,
+        adminRole: rolePda(registryA, signer, "admin"),
+        recipient: treasury,
+        authority: signer,
+      });
+      await expectError(
+        program.methods
+          .withdrawForfeitedDeposits(new BN(LAMPORTS_PER_SOL / 10))
+          .accounts(withdrawAccounts(outsider.publicKey))
+          .signers([outsider])
+          .rpc(),
+        "AccountNotInitialized"
+      );
+      await expectError(
+        program.methods
+          .withdrawForfeitedDeposits(new BN(LAMPORTS_PER_SOL / 10 + 1))
+          .accounts(withdrawAccounts(authorityA.publicKey))
+          .rpc(),
+        "InsufficientForfeitedDeposits"
+      );
+      await program.methods
+        .withdrawForfeitedDeposits(new BN(LAMPORTS_PER_SOL / 10))
+        .accounts(withdrawAccounts(authorityA.publicKey))
+        .rpc();
+      expect(await connection.getBalance(registryA)).to.equal(registryBalance);
+      expect(await connection.getBalance(treasury)).to.equal(LAMPORTS_PER_SOL / 10);
+
+      await program.methods
+        .setApplicationDeposit(new BN(0))
+        .accounts({
+          registry: registryA,
+          adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+          authority: authorityA.publicKey,
+        })
+        .rpc();
+    });
+  });
+
+  describe("methodology catalog", () => {
+    it("rejects applications under a deprecated methodology", async () => {
+      const methodology = await addMethodology(registryA, authorityA, "ACM0002", "18.0", { renewableEnergy: {} });
+      await program.methods
+        .setMethodologyStatus({ deprecated: {} })
+        .accounts({
+          methodology,
+          registry: registryA,
+          adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+          authority: authorityA.publicKey,
+        })
+        .rpc();
+      await expectError(submitApplication(registryA, developer, "PRJ-OLD", methodology), "MethodologyDeprecated");
+    });
+
+    it("rejects a methodology for another sector", async () => {
+      const methodology = await addMethodology(registryA, authorityA, "VM0007", "1.6", { forestry: {} });
+      await expectError(submitApplication(registryA, developer, "PRJ-SECTOR", methodology), "MethodologySectorMismatch");
+    });
+
+    it("rejects a methodology from another registry", async () => {
+      await expectError(
+        submitApplication(registryA, developer, "PRJ-XREG", methodologyPda(registryB, "ACM0002", "19.0")),
+        "MethodologyRegistryMismatch"
+      );
+    });
+  });
+
+  describe("crediting periods", () => {
+    it("rejects batches with vintages outside the crediting period", async () => {
+      await expectError(
+        program.methods
+          .createBatch("BATCH-OLD", new BN(0), new BN(1), "https://reports.example.org/old")
+          .accounts({
+            projectRegistry: projectA,
+            creditBatch: batchPda(projectA, "BATCH-OLD"),
+            registry: registryA,
+            issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+            authority: authorityA.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(),
+        "VintageOutsideCreditingPeriod"
+      );
+    });
+
+    it("rejects applications whose vintage falls outside the crediting period", async () => {
+      await expectError(
+        submitApplication(
+          registryA,
+          developer,
+          "PRJ-VINTAGE",
+          undefined,
+          now() - YEAR,
+          "KE",
+          null,
+          { renewableEnergy: {} },
+          2010
+        ),
+        "VintageOutsideCreditingPeriod"
+      );
+    });
+
+    it("renews once per fresh verification", async () => {
+      const creditingPeriodStart = now() - 7 * YEAR + 30 * 24 * 60 * 60;
+      const projectApplication = await submitApplication(
+        registryA,
+        developer,
+        "PRJ-RENEW",
+        methodologyPda(registryA, "ACM0002", "19.0"),
+        creditingPeriodStart
+      );
+      const project = projectPda(registryA, "PRJ-RENEW");
+      await program.methods
+        .approveProjectApplication()
+        .accounts({
+          projectApplication,
+          methodology: methodologyPda(registryA, "ACM0002", "19.0"),
+          projectRegistry: project,
+          creditMint: creditMintPda(project),
+          registry: registryA,
+          countryStats: countryStatsPda(registryA, "KE"),
+          developer: developer.publicKey,
+          approverRole: rolePda(registryA, authorityA.publicKey, "projectApprover"),
+          authority: authorityA.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+      const verifierSeed = Keypair.generate().publicKey;
+      const verifier = PublicKey.findProgramAddressSync(
+        [Buffer.from("verifier"), verifierSeed.toBuffer()],
+        verificationProgram.programId
+      )[0];
+      const verificationRequest = PublicKey.findProgramAddressSync(
+        [Buffer.from("verification_request"), project.toBuffer(), developer.publicKey.toBuffer()],
+        verificationProgram.programId
+      )[0];
+      const verificationResult = PublicKey.findProgramAddressSync(
+        [Buffer.from("verification_result"), verificationRequest.toBuffer()],
+        verificationProgram.programId
+      )[0];
+      const verificationConfig = PublicKey.findProgramAddressSync(
+        [Buffer.from("verification_config")],
+        verificationProgram.programId
+      )[0];
+      if (!(await verificationProgram.account.verificationConfig.fetchNullable(verificationConfig))) {
+        await verificationProgram.methods
+          .initializeVerificationConfig()
+          .accounts({
+            verificationConfig,
+            program: verificationProgram.programId,
+            programData: PublicKey.findProgramAddressSync(
+              [verificationProgram.programId.toBuffer()],
+              new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+            )[0],
+            authority: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      }
+      const accreditationAuthority = Keypair.generate();
+      const accreditationBody = PublicKey.findProgramAddressSync(
+        [Buffer.from("accreditation_body"), accreditationAuthority.publicKey.toBuffer()],
+        verificationProgram.programId
+      )[0];
+      await verificationProgram.methods
+        .registerAccreditationBody(accreditationAuthority.publicKey, "ANAB")
+        .accounts({
+          accreditationBody,
+          verificationConfig,
+          authority: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await verificationProgram.methods
+        .initializeVerifier(verifierSeed, "Renewal Verifier", { advanced: {} }, [{ renewableEnergy: {} }], new BN(now() + YEAR))
+        .accounts({
+          verifier,
+          stakeVault: PublicKey.findProgramAddressSync(
+            [Buffer.from("stake_vault"), verifier.toBuffer()],
+            verificationProgram.programId
+          )[0],
+          accreditationBody,
+          accreditationAuthority: accreditationAuthority.publicKey,
+          authority: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([accreditationAuthority])
+        .rpc();
+      await verificationProgram.methods
+        .submitVerificationRequest(
+          project,
+          { periodic: {} },
+          "https://docs.example.org/renewal.pdf",
+          new BN(1000),
+          [],
+          1,
+          { minimum: {} },
+          new BN(0)
+        )
+        .accounts({
+          verificationRequest,
+          project,
+          verifier,
+          accreditationBody,
+          verificationConfig,
+          feeMint: null,
+          feeVault: null,
+          requesterTokenAccount: null,
+          requester: developer.publicKey,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([developer])
+        .rpc();
+      await verificationProgram.methods
+        .acceptRequest(null, null)
+        .accounts({ verificationRequest, verifier, accreditationBody, verificationConfig, verifierAuthority: payer.publicKey })
+        .rpc();
+      await verificationProgram.methods
+        .conductVerification(new BN(1000), "baseline reassessed", 90)
+        .accounts({
+          verificationRequest,
+          verificationResult,
+          verifier,
+          accreditationBody,
+          verificationConfig,
+          feeVault: null,
+          verifierTokenAccount: null,
+          verifierAuthority: payer.publicKey,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const renewAccounts = {
+        projectRegistry: project,
+        methodology: methodologyPda(registryA, "ACM0002", "19.0"),
+        verificationResult,
+        verifier,
+        accreditationBody,
+        verificationConfig,
+        registry: registryA,
+        approverRole: rolePda(registryA, authorityA.publicKey, "projectApprover"),
+        authority: authorityA.publicKey,
+      };
+      const setAccreditation = (status: object) =>
+        verificationProgram.methods
+          .setVerifierAccreditation(status as any, new BN(now() + YEAR))
+          .accounts({ verifier, accreditationBody, accreditationAuthority: accreditationAuthority.publicKey })
+          .signers([accreditationAuthority])
+          .rpc();
+      await setAccreditation({ suspended: {} });
+      await expectError(
+        program.methods.renewCreditingPeriod(7).accounts(renewAccounts).rpc(),
+        "VerifierNotAccredited"
+      );
+      await setAccreditation({ active: {} });
+      await program.methods.renewCreditingPeriod(7).accounts(renewAccounts).rpc();
+
+      const state = await program.account.projectRegistry.fetch(project);
+      expect(state.creditingPeriodRenewals).to.equal(1);
+      expect(state.creditingPeriodStart.toNumber()).to.equal(creditingPeriodStart + 7 * YEAR);
+      expect(state.creditingPeriodEnd.toNumber()).to.equal(creditingPeriodStart + 14 * YEAR);
+
+      await expectError(
+        program.methods.renewCreditingPeriod(7).accounts(renewAccounts).rpc(),
+        "StaleVerification"
+      );
+    });
+  });
+
+  describe("country codes", () => {
+    it("rejects codes that are not in ISO 3166-1", async () => {
+      await expectError(
+        submitApplication(registryA, developer, "PRJ-XX", undefined, undefined, "QQ"),
+        "InvalidCountryCode"
+      );
+      await expectError(
+        program.methods
+          .initializeCountryStats(Array.from(Buffer.from("ZZ")))
+          .accounts({
+            countryStats: countryStatsPda(registryA, "ZZ"),
+            registry: registryA,
+            adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+            authority: authorityA.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(),
+        "InvalidCountryCode"
+      );
+    });
+
+    it("only lets an admin create country stats", async () => {
+      await expectError(
+        program.methods
+          .initializeCountryStats(Array.from(Buffer.from("TZ")))
+          .accounts({
+            countryStats: countryStatsPda(registryA, "TZ"),
+            registry: registryA,
+            adminRole: rolePda(registryA, outsider.publicKey, "admin"),
+            authority: outsider.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([outsider])
+          .rpc(),
+        "AccountNotInitialized"
+      );
+    });
+
+    it("rejects a subdivision of another country", async () => {
+      await expectError(
+        submitApplication(registryA, developer, "PRJ-SUB", undefined, undefined, "KE", "TZ-01"),
+        "InvalidSubdivisionCode"
+      );
+    });
+
+    it("rejects subdivision codes that are not in ISO 3166-2", async () => {
+      await expectError(
+        submitApplication(registryA, developer, "PRJ-SUB", undefined, undefined, "KE", "KE-99"),
+        "InvalidSubdivisionCode"
+      );
+    });
+
+    it("stores alpha-3 codes as alpha-2 and tallies issuance per country", async () => {
+      const project = await registerProject(registryA, authorityA, "PRJ-KEN", developer, "KEN", "KE-30");
+      const state = await program.account.projectRegistry.fetch(project);
+      expect(Buffer.from(state.countryCode).toString()).to.equal("KE");
+      expect(Buffer.from(state.subdivisionCode).toString()).to.equal("30\0");
+
+      const statsBefore = await program.account.countryStats.fetch(countryStatsPda(registryA, "KE"));
+      const mint = creditMintPda(project);
+      const recipientAccount = (await getOrCreateAssociatedTokenAccount(connection, payer, mint, holder.publicKey))
+        .address;
+      await program.methods
+        .issueCredits("KEN", new BN(100), new BN(now() - 60), new BN(now() - 60), new BN(now() - 60))
+        .accounts({
+          projectRegistry: project,
+          creditIssuance: issuancePda(project, authorityA.publicKey),
+          registry: registryA,
+          countryStats: countryStatsPda(registryA, "KE"),
+          creditMint: mint,
+          recipientTokenAccount: recipientAccount,
+          recipient: holder.publicKey,
+          bufferPool: null,
+          bufferVault: null,
+          adaptationFundTokenAccount: null,
+          issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+          authority: authorityA.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const statsAfter = await program.account.countryStats.fetch(countryStatsPda(registryA, "KE"));
+      expect(statsAfter.totalIssued.toNumber()).to.equal(statsBefore.totalIssued.toNumber() + 100);
+      expect(statsAfter.totalProjects.toNumber()).to.be.greaterThan(0);
+    });
+  });
+
+  describe("project ownership transfer", () => {
+    let newDeveloper: Keypair;
+    let project: PublicKey;
+
+    before(async () => {
+      newDeveloper = await fundedKeypair();
+      project = await registerProject(registryA, authorityA, "PRJ-MNA", developer);
+    });
+
+    const adminAccounts = () => ({
+      registry: registryA,
+      adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+      authority: authorityA.publicKey,
+    });
+
+    it("rejects a proposal from someone other than the developer", async () => {
+      await expectError(
+        program.methods
+          .proposeProjectTransfer(outsider.publicKey)
+          .accounts({ projectRegistry: project, developer: outsider.publicKey })
+          .signers([outsider])
+          .rpc(),
+        "UnauthorizedProjectSigner"
+      );
+    });
+
+    it("requires registry approval when the registry demands it", async () => {
+      await program.methods.setTransferApprovalRequired(true).accounts(adminAccounts()).rpc();
+      await program.methods
+        .proposeProjectTransfer(newDeveloper.publicKey)
+        .accounts({ projectRegistry: project, developer: developer.publicKey })
+        .signers([developer])
+        .rpc();
+
+      const acceptAccounts = (signer: PublicKey) => ({ projectRegistry: project, registry: registryA, newDeveloper: signer });
+      await expectError(
+        program.methods.acceptProjectTransfer().accounts(acceptAccounts(outsider.publicKey)).signers([outsider]).rpc(),
+        "NotPendingDeveloper"
+      );
+      await expectError(
+        program.methods
+          .acceptProjectTransfer()
+          .accounts(acceptAccounts(newDeveloper.publicKey))
+          .signers([newDeveloper])
+          .rpc(),
+        "ProjectTransferNotApproved"
+      );
+
+      await program.methods
+        .approveProjectTransfer()
+        .accounts({
+          projectRegistry: project,
+          registry: registryA,
+          approverRole: rolePda(registryA, authorityA.publicKey, "projectApprover"),
+          authority: authorityA.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .acceptProjectTransfer()
+        .accounts(acceptAccounts(newDeveloper.publicKey))
+        .signers([newDeveloper])
+        .rpc();
+
+      const state = await program.account.projectRegistry.fetch(project);
+      expect(state.projectDeveloper.toBase58()).to.equal(newDeveloper.publicKey.toBase58());
+      expect(state.pendingDeveloper).to.be.null;
+
+      await program.methods.setTransferApprovalRequired(false).accounts(adminAccounts()).rpc();
+    });
+  });
+
+  describe("update_project_status", () => {
+    const statusAccounts = (project: PublicKey, registry: PublicKey, signer: PublicKey, sequence = 0) => ({
+      projectRegistry: project,
+      statusHistory: statusHistoryPda(project, sequence),
+      registry,
+      approverRole: rolePda(registry, signer, "projectApprover"),
+      authority: signer,
+      systemProgram: SystemProgram.programId,
+    });
+
+    it("rejects a project from another registry", async () => {
+      await expectError(
+        program.methods
+          .updateProjectStatus({ suspended: {} }, "cross-registry")
+          .accounts(statusAccounts(projectB, registryA, authorityA.publicKey))
+          .rpc(),
+        "ProjectRegistryMismatch"
+      );
+    });
+
+    it("rejects a signer without the project approver role", async () => {
+      await expectError(
+        program.methods
+          .updateProjectStatus({ suspended: {} }, "not approver")
+          .accounts(statusAccounts(projectA, registryA, outsider.publicKey))
+          .signers([outsider])
+          .rpc(),
+        "AccountNotInitialized"
+      );
+    });
+
+    it("records history, pauses issuance under review and keeps terminated projects terminated", async () => {
+      const project = await registerProject(registryA, authorityA, "PRJ-SM", developer);
+      const mint = creditMintPda(project);
+      const recipientAccount = (await getOrCreateAssociatedTokenAccount(connection, payer, mint, holder.publicKey))
+        .address;
+
+      await program.methods
+        .updateProjectStatus({ underReview: {} }, "monitoring report disputed")
+        .accounts(statusAccounts(project, registryA, authorityA.publicKey, 0))
+        .rpc();
+
+      await expectError(
+        program.methods
+          .issueCredits("SM", new BN(1), new BN(0), new BN(now()), new BN(now()))
+          .accounts({
+            projectRegistry: project,
+            creditIssuance: issuancePda(project, authorityA.publicKey),
+            registry: registryA,
+            countryStats: countryStatsPda(registryA, "KE"),
+            creditMint: mint,
+            recipientTokenAccount: recipientAccount,
+            recipient: holder.publicKey,
+            bufferPool: null,
+            bufferVault: null,
+            adaptationFundTokenAccount: null,
+            issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+            authority: authorityA.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(),
+        "ProjectUnderReview"
+      );
+
+      await program.methods
+        .updateProjectStatus({ terminated: {} }, "fraud confirmed")
+        .accounts(statusAccounts(project, registryA, authorityA.publicKey, 1))
+        .rpc();
+
+      await expectError(
+        program.methods
+          .updateProjectStatus({ active: {} }, "reinstate")
+          .accounts(statusAccounts(project, registryA, authorityA.publicKey, 2))
+          .rpc(),
+        "InvalidStatusTransition"
+      );
+
+      const first = await program.account.projectStatusHistory.fetch(statusHistoryPda(project, 0));
+      expect(first.oldStatus).to.deep.equal({ active: {} });
+      expect(first.newStatus).to.deep.equal({ underReview: {} });
+      expect(first.actor.toBase58()).to.equal(authorityA.publicKey.toBase58());
+      const second = await program.account.projectStatusHistory.fetch(statusHistoryPda(project, 1));
+      expect(second.reason).to.equal("fraud confirmed");
+    });
+  });
+
+  describe("suspended projects", () => {
+    let project: PublicKey;
+    let holderAccount: PublicKey;
+
+    const setStatus = (status: object, sequence: number) =>
+      program.methods
+        .updateProjectStatus(status as any, "compliance check")
+        .accounts({
+          projectRegistry: project,
+          statusHistory: statusHistoryPda(project, sequence),
+          registry: registryA,
+          approverRole: rolePda(registryA, authorityA.publicKey, "projectApprover"),
+          authority: authorityA.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    const freezeAccounts = () => ({
+      projectRegistry: project,
+      registry: registryA,
+      adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+      creditMint: creditMintPda(project),
+      holderTokenAccount: holderAccount,
+      authority: authorityA.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+
+    before(async () => {
+      project = await registerProject(registryA, authorityA, "PRJ-FREEZE", developer);
+      holderAccount = await issueCredits(project, holder.publicKey, 10);
+    });
+
+    it("only freezes holder accounts of suspended or terminated projects", async () => {
+      await expectError(
+        program.methods.freezeCreditAccount().accounts(freezeAccounts()).rpc(),
+        "ProjectNotSuspended"
+      );
+    });
+
+    it("blocks transfers and retirements while suspended and freezes holders until reinstated", async () => {
+      await setStatus({ suspended: {} }, 0);
+
+      const outsiderAccount = (
+        await getOrCreateAssociatedTokenAccount(connection, payer, creditMintPda(project), outsider.publicKey)
+      ).address;
+      await expectError(
+        program.methods
+          .transferCredits(new BN(1), "secondary sale", null)
+          .accounts({
+            projectRegistry: project,
+            transferRecord: transferRecordPda(project, holder.publicKey),
+            fromTokenAccount: holderAccount,
+            toTokenAccount: outsiderAccount,
+            fromOwner: holder.publicKey,
+            toOwner: outsider.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([holder])
+          .rpc(),
+        "ProjectNotActive"
+      );
+      await expectError(
+        program.methods
+          .retireCredits(new BN(1), "offset", "Holder Ltd", null, { voluntary: {} })
+          .accounts(retireAccounts(project, registryA, holderAccount, holder.publicKey))
+          .signers([holder])
+          .rpc(),
+        "ProjectNotActive"
+      );
+
+      await program.methods.freezeCreditAccount().accounts(freezeAccounts()).rpc();
+      expect((await getAccount(connection, holderAccount)).isFrozen).to.equal(true);
+      await expectError(
+        program.methods.thawCreditAccount().accounts(freezeAccounts()).rpc(),
+        "ProjectNotActive"
+      );
+
+      await setStatus({ active: {} }, 1);
+      await program.methods.thawCreditAccount().accounts(freezeAccounts()).rpc();
+      expect((await getAccount(connection, holderAccount)).isFrozen).to.equal(false);
+    });
+  });
+
+  describe("issue_credits", () => {
+    const issueAccounts = (overrides: Record<string, PublicKey | null>) => ({
+      projectRegistry: projectA,
+      creditIssuance: issuancePda(projectA, authorityA.publicKey),
+      registry: registryA,
+      countryStats: countryStatsPda(registryA, "KE"),
+      creditMint: mintA,
+      recipientTokenAccount: holderAccountA,
+      recipient: holder.publicKey,
+      bufferPool: null,
+      bufferVault: null,
+      adaptationFundTokenAccount: null,
+      issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+      authority: authorityA.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      ...overrides,
+    });
+
+    it("rejects a project from another registry", async () => {
+      await expectError(
+        program.methods
+          .issueCredits("X", new BN(1), new BN(0), new BN(now()), new BN(now()))
+          .accounts(
+            issueAccounts({
+              projectRegistry: projectB,
+              creditIssuance: issuancePda(projectB, authorityA.publicKey),
+            })
+          )
+          .rpc(),
+        "ProjectRegistryMismatch"
+      );
+    });
+
+    it("rejects a mint that is not the project's credit mint", async () => {
+      const foreignMint = await createMint(connection, payer, registryA, registryA, 0);
+      const foreignAccount = await createAccount(connection, payer, foreignMint, holder.publicKey, Keypair.generate());
+      await expectError(
+        program.methods
+          .issueCredits("X", new BN(1), new BN(0), new BN(now()), new BN(now()))
+          .accounts(
+            issueAccounts({
+              creditMint: foreignMint,
+              recipientTokenAccount: foreignAccount,
+            })
+          )
+          .rpc(),
+        "CreditMintMismatch"
+      );
+    });
+
+    it("rejects a recipient token account owned by someone else", async () => {
+      await expectError(
+        program.methods
+          .issueCredits("X", new BN(1), new BN(0), new BN(now()), new BN(now()))
+          .accounts(issueAccounts({ recipientTokenAccount: outsiderAccountA }))
+          .rpc(),
+        "TokenAccountOwnerMismatch"
+      );
+    });
+  });
+
+  describe("issuance levies", () => {
+    const setLevies = (shareOfProceedsBps: number, omgeBps: number, adaptationFund: PublicKey | null) =>
+      program.methods
+        .setIssuanceLevies(shareOfProceedsBps, omgeBps, adaptationFund)
+        .accounts({
+          registry: registryB,
+          adminRole: rolePda(registryB, authorityB.publicKey, "admin"),
+          authority: authorityB.publicKey,
+        })
+        .signers([authorityB])
+        .rpc();
+
+    after(async () => {
+      await setLevies(0, 0, null);
+    });
+
+    it("requires an adaptation fund for a share of proceeds", async () => {
+      await expectError(setLevies(500, 200, null), "AdaptationFundRequired");
+    });
+
+    it("mints the share of proceeds to the adaptation fund and cancels the OMGE share", async () => {
+      await setLevies(500, 200, developer.publicKey);
+      const fundAccount = (await getOrCreateAssociatedTokenAccount(connection, payer, mintB, developer.publicKey))
+        .address;
+      const issuance = issuancePda(projectB, authorityB.publicKey);
+      const registryBefore = await program.account.registry.fetch(registryB);
+
+      await program.methods
+        .issueCredits("LEVY", new BN(1000), new BN(0), new BN(now()), new BN(now()))
+        .accounts({
+          projectRegistry: projectB,
+          creditIssuance: issuance,
+          registry: registryB,
+          countryStats: countryStatsPda(registryB, "KE"),
+          creditMint: mintB,
+          recipientTokenAccount: outsiderAccountB,
+          recipient: outsider.publicKey,
+          bufferPool: null,
+          bufferVault: null,
+          adaptationFundTokenAccount: fundAccount,
+          issuerRole: rolePda(registryB, authorityB.publicKey, "issuer"),
+          authority: authorityB.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authorityB])
+        .rpc();
+
+      const state = await program.account.creditIssuance.fetch(issuance);
+      expect(state.shareOfProceeds.toNumber()).to.equal(50);
+      expect(state.omgeCancelled.toNumber()).to.equal(20);
+      expect(Number((await getAccount(connection, outsiderAccountB)).amount)).to.equal(930);
+      expect(Number((await getAccount(connection, fundAccount)).amount)).to.equal(50);
+
+      const registryAfter = await program.account.registry.fetch(registryB);
+      expect(registryAfter.totalCreditsIssued.sub(registryBefore.totalCreditsIssued).toNumber()).to.equal(980);
+      expect(registryAfter.totalShareOfProceeds.sub(registryBefore.totalShareOfProceeds).toNumber()).to.equal(50);
+      expect(registryAfter.totalOmgeCancelled.sub(registryBefore.totalOmgeCancelled).toNumber()).to.equal(20);
+    });
+  });
+
+  describe("transfer_credits", () => {
+    const transferAccounts = (overrides: Record<string, PublicKey>) => ({
+      projectRegistry: projectA,
+      transferRecord: transferRecordPda(projectA, holder.publicKey),
+      fromTokenAccount: holderAccountA,
+      toTokenAccount: outsiderAccountA,
+      fromOwner: holder.publicKey,
+      toOwner: outsider.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      ...overrides,
+    });
+
+    it("rejects a source account not owned by the signer", async () => {
+      await expectError(
+        program.methods
+          .transferCredits(new BN(1), "substituted source", null)
+          .accounts(
+            transferAccounts({
+              transferRecord: transferRecordPda(projectA, outsider.publicKey),
+              fromOwner: outsider.publicKey,
+            })
+          )
+          .signers([outsider])
+          .rpc(),
+        "TokenAccountOwnerMismatch"
+      );
+    });
+
+    it("rejects a destination account not owned by the recipient", async () => {
+      await expectError(
+        program.methods
+          .transferCredits(new BN(1), "substituted destination", null)
+          .accounts(transferAccounts({ toOwner: developer.publicKey }))
+          .signers([holder])
+          .rpc(),
+        "TokenAccountOwnerMismatch"
+      );
+    });
+
+    it("rejects token accounts of another project's mint", async () => {
+      await expectError(
+        program.methods
+          .transferCredits(new BN(1), "wrong mint", null)
+          .accounts(transferAccounts({ toTokenAccount: outsiderAccountB }))
+          .signers([holder])
+          .rpc(),
+        "TokenAccountMintMismatch"
+      );
+    });
+  });
+
+  const retireAccounts = (
+    projectRegistry: PublicKey,
+    registry: PublicKey,
+    ownerTokenAccount: PublicKey,
+    owner: PublicKey
+  ) => {
+    const retirement = retirementPda(projectRegistry, owner);
+    const certificateMint = pda([Buffer.from("certificate_mint"), retirement.toBuffer()]);
+    return {
+      projectRegistry,
+      creditRetirement: retirement,
+      registry,
+      countryStats: countryStatsPda(registry, "KE"),
+      creditMint: creditMintPda(projectRegistry),
+      ownerTokenAccount,
+      owner,
+      retirementCertificate: pda([Buffer.from("retirement_certificate"), retirement.toBuffer()]),
+      certificateMint,
+      certificateTokenAccount: getAssociatedTokenAddressSync(certificateMint, owner, true),
+      certificateHolder: owner,
+      certificateMetadata: PublicKey.findProgramAddressSync(
+        [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), certificateMint.toBuffer()],
+        TOKEN_METADATA_PROGRAM_ID
+      )[0],
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    };
+  };
+
+  // Issues to `recipient`'s associated account on a registry A project; one issuance per project
+  async function issueCredits(
+    project: PublicKey,
+    recipient: PublicKey,
+    quantity: number,
+    overrides: Record<string, PublicKey | null> = {}
+  ): Promise<PublicKey> {
+    const mint = creditMintPda(project);
+    const recipientAccount = (await getOrCreateAssociatedTokenAccount(connection, payer, mint, recipient)).address;
+    await program.methods
+      .issueCredits("SN", new BN(quantity), new BN(now() - 60), new BN(now() - 60), new BN(now() - 60))
+      .accounts({
+        projectRegistry: project,
+        creditIssuance: issuancePda(project, authorityA.publicKey),
+        registry: registryA,
+        countryStats: countryStatsPda(registryA, "KE"),
+        creditMint: mint,
+        recipientTokenAccount: recipientAccount,
+        recipient,
+        bufferPool: null,
+        bufferVault: null,
+        adaptationFundTokenAccount: null,
+        issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+        authority: authorityA.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        ...overrides,
+      })
+      .rpc();
+    return recipientAccount;
+  }
+
+  // Reads the fields of a Metaplex metadata account created without creators
+  function decodeMetadata(data: Buffer) {
+    let offset = 1 + 32 + 32;
+    const readString = () => {
+      const length = data.readUInt32LE(offset);
+      const value = data.subarray(offset + 4, offset + 4 + length).toString().replace(/\0/g, "");
+      offset += 4 + length;
+      return value;
+    };
+    const name = readString();
+    const symbol = readString();
+    const uri = readString();
+    offset += 2 + 1 + 1;
+    return { name, symbol, uri, isMutable: data[offset] === 1 };
+  }
+
+  describe("retire_credits", () => {
+    it("mints a frozen certificate that records the retirement details", async () => {
+      const project = await registerProject(registryA, authorityA, "PRJ-CERT", developer);
+      const ownerAccount = await issueCredits(project, holder.publicKey, 10);
+      const accounts = retireAccounts(project, registryA, ownerAccount, holder.publicKey);
+      // A realistic beneficiary and reason, longer than would fit into a 200-char metadata URI alongside the path
+      const beneficiary = "Acme & Sons Logistics (Europe) GmbH – Scope 3 travel, FY2024 reporting entity";
+      const reason =
+        "Offsetting 2024 business travel emissions (flights & rail) for Acme & Sons Logistics, " +
+        "per our SBTi-aligned net-zero plan; see sustainability report §4.2 for methodology.";
+      await program.methods
+        .retireCredits(new BN(4), reason, beneficiary, null, { voluntary: {} })
+        .accounts(accounts)
+        .signers([holder])
+        .rpc();
+
+      const certificateAccount = await getAccount(connection, accounts.certificateTokenAccount);
+      expect(Number(certificateAccount.amount)).to.equal(1);
+      expect(certificateAccount.isFrozen).to.equal(true);
+
+      const { vintageYear } = await program.account.projectRegistry.fetch(project);
+      const metadata = decodeMetadata((await connection.getAccountInfo(accounts.certificateMetadata)).data);
+      expect(metadata.name).to.equal(`4 tCO2e V${vintageYear}`);
+      expect(metadata.isMutable).to.equal(false);
+      expect(metadata.uri).to.equal(
+        `https://registry.example.org/retirements/${accounts.creditRetirement.toBase58()}.json`
+      );
+
+      const certificate = await program.account.retirementCertificate.fetch(accounts.retirementCertificate);
+      expect(certificate.metadataUri).to.equal(metadata.uri);
+      expect(certificate.holder.toBase58()).to.equal(holder.publicKey.toBase58());
+      expect(certificate.projectId).to.equal("PRJ-CERT");
+      expect(certificate.vintageYear).to.equal(vintageYear);
+      expect(certificate.quantity.toNumber()).to.equal(4);
+      expect(certificate.beneficiary).to.equal(beneficiary);
+      expect(certificate.retirementReason).to.equal(reason);
+    });
+
+    it("rejects an owner token account belonging to someone else", async () => {
+      await expectError(
+        program.methods
+          .retireCredits(new BN(1), "offset", "Outsider Ltd", null, { voluntary: {} })
+          .accounts(retireAccounts(projectA, registryA, holderAccountA, outsider.publicKey))
+          .signers([outsider])
+          .rpc(),
+        "TokenAccountOwnerMismatch"
+      );
+    });
+  });
+
+  describe("cancel_issuance", () => {
+    let project: PublicKey;
+    let holderAccount: PublicKey;
+
+    const cancelAccounts = (sourceTokenAccount: PublicKey) => ({
+      projectRegistry: project,
+      creditIssuance: issuancePda(project, authorityA.publicKey),
+      registry: registryA,
+      countryStats: countryStatsPda(registryA, "KE"),
+      issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+      creditMint: creditMintPda(project),
+      sourceTokenAccount,
+      authority: authorityA.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+
+    before(async () => {
+      project = await registerProject(registryA, authorityA, "PRJ-CANCEL", developer);
+      holderAccount = await issueCredits(project, holder.publicKey, 10);
+    });
+
+    it("refuses to burn from an account the registry is not delegated on", async () => {
+      await expectError(
+        program.methods.cancelIssuance(new BN(4), "duplicate serials").accounts(cancelAccounts(holderAccount)).rpc(),
+        "RegistryCannotBurn"
+      );
+    });
+
+    it("burns delegated credits and reverses the issued totals", async () => {
+      await approve(connection, payer, holderAccount, registryA, holder, 4);
+      const statsBefore = await program.account.countryStats.fetch(countryStatsPda(registryA, "KE"));
+
+      await program.methods
+        .cancelIssuance(new BN(4), "duplicate serials")
+        .accounts(cancelAccounts(holderAccount))
+        .rpc();
+
+      expect(Number((await getAccount(connection, holderAccount)).amount)).to.equal(6);
+      const issuance = await program.account.creditIssuance.fetch(issuancePda(project, authorityA.publicKey));
+      expect(issuance.cancelledQuantity.toNumber()).to.equal(4);
+      expect(issuance.cancellationReason).to.equal("duplicate serials");
+      expect(issuance.status).to.deep.equal({ active: {} });
+      const state = await program.account.projectRegistry.fetch(project);
+      expect(state.totalIssued.toNumber()).to.equal(6);
+      const statsAfter = await program.account.countryStats.fetch(countryStatsPda(registryA, "KE"));
+      expect(statsAfter.totalIssued.toNumber()).to.equal(statsBefore.totalIssued.toNumber() - 4);
+    });
+
+    it("rejects cancelling more than is outstanding", async () => {
+      await approve(connection, payer, holderAccount, registryA, holder, 6);
+      await expectError(
+        program.methods.cancelIssuance(new BN(7), "duplicate serials").accounts(cancelAccounts(holderAccount)).rpc(),
+        "CancellationExceedsIssuance"
+      );
+    });
+  });
+
+  describe("buffer pool", () => {
+    const FORESTRY_SEED = 0;
+    const bufferVaultPda = (project: PublicKey) => pda([Buffer.from("buffer_vault"), project.toBuffer()]);
+    let bufferPool: PublicKey;
+    let methodology: PublicKey;
+    let project: PublicKey;
+
+    const initializeBufferVault = (project: PublicKey) =>
+      program.methods
+        .initializeBufferVault()
+        .accounts({
+          projectRegistry: project,
+          bufferVault: bufferVaultPda(project),
+          creditMint: creditMintPda(project),
+          registry: registryA,
+          issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+          authority: authorityA.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+    const reversalAccounts = (sourceProject: PublicKey) => ({
+      projectRegistry: project,
+      bufferPool,
+      sourceProjectRegistry: sourceProject,
+      bufferVault: bufferVaultPda(sourceProject),
+      creditMint: creditMintPda(sourceProject),
+      registry: registryA,
+      countryStats: countryStatsPda(registryA, "KE"),
+      auditorRole: rolePda(registryA, authorityA.publicKey, "auditor"),
+      authority: authorityA.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    });
+
+    before(async () => {
+      bufferPool = pda([Buffer.from("buffer_pool"), registryA.toBuffer(), Buffer.from([FORESTRY_SEED])]);
+      methodology = await addMethodology(registryA, authorityA, "AR-ACM0003", "2.0", { forestry: {} });
+      project = await registerProject(registryA, authorityA, "PRJ-FOREST", developer, "KE", null, methodology, {
+        forestry: {},
+      });
+      await grantRole(registryA, authorityA, authorityA.publicKey, "auditor");
+    });
+
+    it("requires a buffer pool for nature-based projects", async () => {
+      await expectError(issueCredits(project, holder.publicKey, 100), "BufferPoolRequired");
+    });
+
+    it("withholds the pool percentage into the project's buffer vault", async () => {
+      await program.methods
+        .initializeBufferPool({ forestry: {} }, 2000)
+        .accounts({
+          bufferPool,
+          registry: registryA,
+          adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+          authority: authorityA.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await initializeBufferVault(project);
+
+      const holderAccount = await issueCredits(project, holder.publicKey, 100, {
+        bufferPool,
+        bufferVault: bufferVaultPda(project),
+      });
+
+      expect(Number((await getAccount(connection, holderAccount)).amount)).to.equal(80);
+      expect(Number((await getAccount(connection, bufferVaultPda(project))).amount)).to.equal(20);
+      const issuance = await program.account.creditIssuance.fetch(issuancePda(project, authorityA.publicKey));
+      expect(issuance.bufferContribution.toNumber()).to.equal(20);
+      const pool = await program.account.bufferPool.fetch(bufferPool);
+      expect(pool.totalContributed.toNumber()).to.equal(20);
+    });
+
+    it("keeps buffer credits out of cancel_issuance", async () => {
+      await expectError(
+        program.methods
+          .cancelIssuance(new BN(5), "duplicate serials")
+          .accounts({
+            projectRegistry: project,
+            creditIssuance: issuancePda(project, authorityA.publicKey),
+            registry: registryA,
+            countryStats: countryStatsPda(registryA, "KE"),
+            issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+            creditMint: creditMintPda(project),
+            sourceTokenAccount: bufferVaultPda(project),
+            authority: authorityA.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc(),
+        "BufferCreditsNotCancellable"
+      );
+    });
+
+    it("cancels buffer credits on a reversal and writes them off the issued totals", async () => {
+      const statsBefore = await program.account.countryStats.fetch(countryStatsPda(registryA, "KE"));
+      const registryBefore = await program.account.registry.fetch(registryA);
+
+      await program.methods.reportReversal(new BN(15), "wildfire").accounts(reversalAccounts(project)).rpc();
+
+      expect(Number((await getAccount(connection, bufferVaultPda(project))).amount)).to.equal(5);
+      const state = await program.account.projectRegistry.fetch(project);
+      expect(state.totalReversed.toNumber()).to.equal(15);
+      expect(state.totalIssued.toNumber()).to.equal(85);
+      const statsAfter = await program.account.countryStats.fetch(countryStatsPda(registryA, "KE"));
+      expect(statsAfter.totalIssued.toNumber()).to.equal(statsBefore.totalIssued.toNumber() - 15);
+      const registryAfter = await program.account.registry.fetch(registryA);
+      expect(registryAfter.totalCreditsIssued.toNumber()).to.equal(registryBefore.totalCreditsIssued.toNumber() - 15);
+      const pool = await program.account.bufferPool.fetch(bufferPool);
+      expect(pool.totalCancelled.toNumber()).to.equal(15);
+
+      await expectError(
+        program.methods.reportReversal(new BN(6), "wildfire").accounts(reversalAccounts(project)).rpc(),
+        "InsufficientBufferCredits"
+      );
+    });
+
+    it("caps a cancellation at what the project still counts as issued after reversals", async () => {
+      // Another forestry project's buffer covers a further reversal, leaving 65 of the 100 issued
+      const otherProject = await registerProject(
+        registryA,
+        authorityA,
+        "PRJ-FOREST-B",
+        developer,
+        "KE",
+        null,
+        methodology,
+        { forestry: {} }
+      );
+      await initializeBufferVault(otherProject);
+      await issueCredits(otherProject, holder.publicKey, 100, {
+        bufferPool,
+        bufferVault: bufferVaultPda(otherProject),
+      });
+      await program.methods.reportReversal(new BN(20), "wildfire").accounts(reversalAccounts(otherProject)).rpc();
+      expect((await program.account.projectRegistry.fetch(project)).totalIssued.toNumber()).to.equal(65);
+
+      const holderAccount = getAssociatedTokenAddressSync(creditMintPda(project), holder.publicKey);
+      await approve(connection, payer, holderAccount, registryA, holder, 80);
+      const cancelIssuance = (quantity: number) =>
+        program.methods
+          .cancelIssuance(new BN(quantity), "duplicate serials")
+          .accounts({
+            projectRegistry: project,
+            creditIssuance: issuancePda(project, authorityA.publicKey),
+            registry: registryA,
+            countryStats: countryStatsPda(registryA, "KE"),
+            issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+            creditMint: creditMintPda(project),
+            sourceTokenAccount: holderAccount,
+            authority: authorityA.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+
+      await expectError(cancelIssuance(80), "CancellationExceedsIssuance");
+      await cancelIssuance(65);
+      expect((await program.account.projectRegistry.fetch(project)).totalIssued.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("retirement attribution", () => {
+    let beneficiary: Keypair;
+    let successor: Keypair;
+    let project: PublicKey;
+    let retirement: PublicKey;
+
+    const certificateAccount = (wallet: PublicKey) =>
+      getAssociatedTokenAddressSync(pda([Buffer.from("certificate_mint"), retirement.toBuffer()]), wallet, true);
+
+    const attributionAccounts = (from: PublicKey, to: PublicKey) => {
+      const certificateMint = pda([Buffer.from("certificate_mint"), retirement.toBuffer()]);
+      return {
+        creditRetirement: retirement,
+        retirementClaim: pda([Buffer.from("retirement_claim"), retirement.toBuffer()]),
+        projectRegistry: project,
+        registry: registryA,
+        retirementCertificate: pda([Buffer.from("retirement_certificate"), retirement.toBuffer()]),
+        certificateMint,
+        holderCertificateAccount: certificateAccount(from),
+        beneficiaryCertificateAccount: certificateAccount(to),
+        newBeneficiaryWallet: to,
+        holder: from,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+    };
+
+    before(async () => {
+      beneficiary = await fundedKeypair();
+      successor = await fundedKeypair();
+      project = await registerProject(registryA, authorityA, "PRJ-ATTR", developer);
+      const ownerAccount = await issueCredits(project, holder.publicKey, 10);
+      retirement = retirementPda(project, holder.publicKey);
+
+      const accounts = retireAccounts(project, registryA, ownerAccount, holder.publicKey);
+      await expectError(
+        program.methods
+          .retireCredits(new BN(3), "offset", "Beneficiary Co", beneficiary.publicKey, { voluntary: {} })
+          .accounts(accounts)
+          .signers([holder])
+          .rpc(),
+        "InvalidCertificateHolder"
+      );
+      await program.methods
+        .retireCredits(new BN(3), "offset", "Beneficiary Co", beneficiary.publicKey, { voluntary: {} })
+        .accounts({
+          ...accounts,
+          certificateTokenAccount: certificateAccount(beneficiary.publicKey),
+          certificateHolder: beneficiary.publicKey,
+        })
+        .signers([holder])
+        .rpc();
+    });
+
+    it("mints the certificate to the beneficiary wallet", async () => {
+      const certificate = await getAccount(connection, certificateAccount(beneficiary.publicKey));
+      expect(Number(certificate.amount)).to.equal(1);
+      expect(certificate.isFrozen).to.equal(true);
+      expect(await connection.getAccountInfo(certificateAccount(holder.publicKey))).to.be.null;
+    });
+
+    it("moves the certificate along with a transferred attribution, once", async () => {
+      await expectError(
+        program.methods
+          .transferAttribution(successor.publicKey)
+          .accounts(attributionAccounts(holder.publicKey, successor.publicKey))
+          .signers([holder])
+          .rpc(),
+        "AccountNotInitialized"
+      );
+
+      await program.methods
+        .transferAttribution(successor.publicKey)
+        .accounts(attributionAccounts(beneficiary.publicKey, successor.publicKey))
+        .signers([beneficiary])
+        .rpc();
+
+      expect(await connection.getAccountInfo(certificateAccount(beneficiary.publicKey))).to.be.null;
+      const reissued = await getAccount(connection, certificateAccount(successor.publicKey));
+      expect(Number(reissued.amount)).to.equal(1);
+      expect(reissued.isFrozen).to.equal(true);
+      const certificate = await program.account.retirementCertificate.fetch(
+        pda([Buffer.from("retirement_certificate"), retirement.toBuffer()])
+      );
+      expect(certificate.holder.toBase58()).to.equal(successor.publicKey.toBase58());
+      const state = await program.account.creditRetirement.fetch(retirement);
+      expect(state.beneficiaryWallet.toBase58()).to.equal(successor.publicKey.toBase58());
+
+      await expectError(
+        program.methods
+          .transferAttribution(beneficiary.publicKey)
+          .accounts(attributionAccounts(successor.publicKey, beneficiary.publicKey))
+          .signers([successor])
+          .rpc(),
+        "AttributionAlreadyTransferred"
+      );
+    });
+
+    it("lets only the attribution holder claim the retirement", async () => {
+      const claimAccounts = (claimant: PublicKey) => ({
+        creditRetirement: retirement,
+        retirementClaim: pda([Buffer.from("retirement_claim"), retirement.toBuffer()]),
+        claimant,
+        systemProgram: SystemProgram.programId,
+      });
+      await expectError(
+        program.methods.claimRetirement(2024).accounts(claimAccounts(holder.publicKey)).signers([holder]).rpc(),
+        "NotAttributionHolder"
+      );
+      await program.methods
+        .claimRetirement(2024)
+        .accounts(claimAccounts(successor.publicKey))
+        .signers([successor])
+        .rpc();
+
+      const claim = await program.account.retirementClaim.fetch(claimAccounts(successor.publicKey).retirementClaim);
+      expect(claim.reportingYear).to.equal(2024);
+      expect(claim.quantity.toNumber()).to.equal(3);
+    });
+  });
+
+  describe("article 6 authorization", () => {
+    let nationalAuthority: Keypair;
+
+    const article6Accounts = (signer: PublicKey) => ({
+      projectRegistry: projectA,
+      countryStats: countryStatsPda(registryA, "KE"),
+      registry: registryA,
+      authority: signer,
+    });
+
+    before(async () => {
+      nationalAuthority = await fundedKeypair();
+      await program.methods
+        .setDesignatedNationalAuthority(nationalAuthority.publicKey)
+        .accounts({
+          countryStats: countryStatsPda(registryA, "KE"),
+          registry: registryA,
+          adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+          authority: authorityA.publicKey,
+        })
+        .rpc();
+    });
+
+    it("rejects country stats from another registry", async () => {
+      await expectError(
+        program.methods
+          .setDesignatedNationalAuthority(outsider.publicKey)
+          .accounts({
+            countryStats: countryStatsPda(registryB, "KE"),
+            registry: registryA,
+            adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+            authority: authorityA.publicKey,
+          })
+          .rpc(),
+        "CountryStatsRegistryMismatch"
+      );
+    });
+
+    it("rejects an authorization not signed by the national authority", async () => {
+      await expectError(
+        program.methods
+          .authorizeArticle6([{ ndc: {} }])
+          .accounts(article6Accounts(authorityA.publicKey))
+          .rpc(),
+        "UnauthorizedNationalAuthority"
+      );
+    });
+
+    it("rejects internationally used retirements from unauthorized projects", async () => {
+      await expectError(
+        program.methods
+          .retireCredits(new BN(1), "offset", "Outsider Ltd", null, { corsia: {} })
+          .accounts(retireAccounts(projectB, registryB, outsiderAccountB, outsider.publicKey))
+          .signers([outsider])
+          .rpc(),
+        "NotArticle6Authorized"
+      );
+    });
+
+    it("records the authorization and corresponding adjustment", async () => {
+      await program.methods
+        .authorizeArticle6([{ ndc: {} }])
+        .accounts(article6Accounts(nationalAuthority.publicKey))
+        .signers([nationalAuthority])
+        .rpc();
+      await program.methods
+        .recordCorrespondingAdjustment(2024)
+        .accounts(article6Accounts(nationalAuthority.publicKey))
+        .signers([nationalAuthority])
+        .rpc();
+
+      const { article6 } = await program.account.projectRegistry.fetch(projectA);
+      expect(article6.authorizedUses).to.deep.equal([{ ndc: {} }]);
+      expect(article6.authorizedBy.toBase58()).to.equal(nationalAuthority.publicKey.toBase58());
+      expect(article6.correspondingAdjustmentYear).to.equal(2024);
+      expect(article6.firstTransferredAt).to.equal(null);
+
+      await expectError(
+        program.methods
+          .authorizeArticle6([{ corsia: {} }])
+          .accounts(article6Accounts(nationalAuthority.publicKey))
+          .signers([nationalAuthority])
+          .rpc(),
+        "AlreadyAuthorized"
+      );
+    });
+
+    it("requires a destination country for authorized credits", async () => {
+      await issueCredits(projectA, holder.publicKey, 10);
+      const transfer = (destination: string | null) =>
+        program.methods
+          .transferCredits(new BN(1), "ITMO sale", destination ? Array.from(Buffer.from(destination)) : null)
+          .accounts({
+            projectRegistry: projectA,
+            transferRecord: transferRecordPda(projectA, holder.publicKey),
+            fromTokenAccount: holderAccountA,
+            toTokenAccount: outsiderAccountA,
+            fromOwner: holder.publicKey,
+            toOwner: outsider.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([holder])
+          .rpc();
+      await expectError(transfer(null), "DestinationCountryRequired");
+      await transfer("CH");
+
+      const { article6 } = await program.account.projectRegistry.fetch(projectA);
+      expect(article6.firstTransferRecord.toBase58()).to.equal(
+        transferRecordPda(projectA, holder.publicKey).toBase58()
+      );
+    });
+
+    it("rejects retirements for a use outside the authorization", async () => {
+      await expectError(
+        program.methods
+          .retireCredits(new BN(1), "offset", "Outsider Ltd", null, { corsia: {} })
+          .accounts(retireAccounts(projectA, registryA, outsiderAccountA, outsider.publicKey))
+          .signers([outsider])
+          .rpc(),
+        "UseNotAuthorized"
+      );
+    });
+  });
+
+  describe("create_batch", () => {
+    it("rejects a signer without the issuer role", async () => {
+      await expectError(
+        program.methods
+          .createBatch("BATCH-1", new BN(0), new BN(1), "https://reports.example.org/1")
+          .accounts({
+            projectRegistry: projectA,
+            creditBatch: batchPda(projectA, "BATCH-1"),
+            registry: registryA,
+            issuerRole: rolePda(registryA, developer.publicKey, "issuer"),
+            authority: developer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([developer])
+          .rpc(),
+        "AccountNotInitialized"
+      );
+    });
+
+    it("rejects a project from another registry", async () => {
+      await expectError(
+        program.methods
+          .createBatch("BATCH-2", new BN(0), new BN(1), "https://reports.example.org/2")
+          .accounts({
+            projectRegistry: projectB,
+            creditBatch: batchPda(projectB, "BATCH-2"),
+            registry: registryA,
+            issuerRole: rolePda(registryA, authorityA.publicKey, "issuer"),
+            authority: authorityA.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(),
+        "ProjectRegistryMismatch"
+      );
+    });
+  });
+
+  describe("add_project_metadata", () => {
+    it("rejects a signer that is neither developer nor metadata editor", async () => {
+      await expectError(
+        program.methods
+          .addProjectMetadata({ photo: {} }, "https://media.example.org/1.jpg", "site photo")
+          .accounts({
+            projectRegistry: projectA,
+            projectMetadata: metadataPda(projectA, outsider.publicKey),
+            registry: registryA,
+            metadataEditorRole: null,
+            authority: outsider.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([outsider])
+          .rpc(),
+        "UnauthorizedProjectSigner"
+      );
+    });
+  });
+
+  describe("authority rotation", () => {
+    it("rejects a proposal from a signer that is not the registry authority", async () => {
+      await expectError(
+        program.methods
+          .proposeRegistryAuthority(outsider.publicKey)
+          .accounts({ registry: registryA, authority: outsider.publicKey })
+          .signers([outsider])
+          .rpc(),
+        "UnauthorizedAuthority"
+      );
+    });
+
+    const acceptAccounts = (registry: PublicKey, oldAuthority: PublicKey, signer: PublicKey, alreadyAdmin = false) => ({
+      registry,
+      adminRole: alreadyAdmin ? null : rolePda(registry, signer, "admin"),
+      existingAdminRole: alreadyAdmin ? rolePda(registry, signer, "admin") : null,
+      oldAdminRole: rolePda(registry, oldAuthority, "admin"),
+      oldAuthority,
+      newAuthority: signer,
+      systemProgram: SystemProgram.programId,
+    });
+
+    it("moves the registry and its admin role to the accepted authority", async () => {
+      const oldAuthority = await fundedKeypair();
+      const newAuthority = await fundedKeypair();
+      const registry = await initializeRegistry(oldAuthority, "Rotating Registry");
+
+      await program.methods
+        .proposeRegistryAuthority(newAuthority.publicKey)
+        .accounts({ registry, authority: oldAuthority.publicKey })
+        .signers([oldAuthority])
+        .rpc();
+
+      await expectError(
+        program.methods
+          .acceptRegistryAuthority()
+          .accounts(acceptAccounts(registry, oldAuthority.publicKey, outsider.publicKey))
+          .signers([outsider])
+          .rpc(),
+        "NotPendingAuthority"
+      );
+
+      await program.methods
+        .acceptRegistryAuthority()
+        .accounts(acceptAccounts(registry, oldAuthority.publicKey, newAuthority.publicKey))
+        .signers([newAuthority])
+        .rpc();
+
+      const state = await program.account.registry.fetch(registry);
+      expect(state.authority.toBase58()).to.equal(newAuthority.publicKey.toBase58());
+      expect(state.pendingAuthority).to.be.null;
+      const adminRole = await program.account.roleAssignment.fetch(rolePda(registry, newAuthority.publicKey, "admin"));
+      expect(adminRole.holder.toBase58()).to.equal(newAuthority.publicKey.toBase58());
+      expect(await connection.getAccountInfo(rolePda(registry, oldAuthority.publicKey, "admin"))).to.be.null;
+    });
+
+    it("lets an authority that already holds admin accept with its existing role", async () => {
+      const oldAuthority = await fundedKeypair();
+      const newAuthority = await fundedKeypair();
+      const registry = await initializeRegistry(oldAuthority, "Co-admin Registry");
+      await grantRole(registry, oldAuthority, newAuthority.publicKey, "admin");
+      await program.methods
+        .proposeRegistryAuthority(newAuthority.publicKey)
+        .accounts({ registry, authority: oldAuthority.publicKey })
+        .signers([oldAuthority])
+        .rpc();
+
+      await expectError(
+        program.methods
+          .acceptRegistryAuthority()
+          .accounts({
+            ...acceptAccounts(registry, oldAuthority.publicKey, newAuthority.publicKey),
+            adminRole: null,
+          })
+          .signers([newAuthority])
+          .rpc(),
+        "InvalidAdminRole"
+      );
+
+      await program.methods
+        .acceptRegistryAuthority()
+        .accounts(acceptAccounts(registry, oldAuthority.publicKey, newAuthority.publicKey, true))
+        .signers([newAuthority])
+        .rpc();
+
+      const state = await program.account.registry.fetch(registry);
+      expect(state.authority.toBase58()).to.equal(newAuthority.publicKey.toBase58());
+      expect(await connection.getAccountInfo(rolePda(registry, oldAuthority.publicKey, "admin"))).to.be.null;
+    });
+  });
+
+  describe("roles", () => {
+    it("rejects grants from a signer without the admin role", async () => {
+      await expectError(
+        program.methods
+          .grantRole(outsider.publicKey, { issuer: {} })
+          .accounts({
+            roleAssignment: rolePda(registryA, outsider.publicKey, "issuer"),
+            registry: registryA,
+            adminRole: rolePda(registryA, outsider.publicKey, "admin"),
+            admin: outsider.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([outsider])
+          .rpc(),
+        "AccountNotInitialized"
+      );
+    });
+
+    it("refuses to let an admin revoke their own admin role", async () => {
+      const adminRole = rolePda(registryA, authorityA.publicKey, "admin");
+      await expectError(
+        program.methods
+          .revokeRole()
+          .accounts({ roleAssignment: adminRole, registry: registryA, adminRole, admin: authorityA.publicKey })
+          .rpc(),
+        "CannotRevokeOwnAdmin"
+      );
+    });
+
+    it("rejects a role assignment from another registry", async () => {
+      await expectError(
+        program.methods
+          .revokeRole()
+          .accounts({
+            roleAssignment: rolePda(registryB, authorityB.publicKey, "issuer"),
+            registry: registryA,
+            adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+            admin: authorityA.publicKey,
+          })
+          .rpc(),
+        "RoleRegistryMismatch"
+      );
+    });
+
+    it("closes a revoked role so the holder loses access", async () => {
+      const editorRole = rolePda(registryA, outsider.publicKey, "metadataEditor");
+      await grantRole(registryA, authorityA, outsider.publicKey, "metadataEditor");
+      await program.methods
+        .revokeRole()
+        .accounts({
+          roleAssignment: editorRole,
+          registry: registryA,
+          adminRole: rolePda(registryA, authorityA.publicKey, "admin"),
+          admin: authorityA.publicKey,
+        })
+        .rpc();
+      expect(await connection.getAccountInfo(editorRole)).to.be.null;
+    });
+  });
+});
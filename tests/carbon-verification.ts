@@ -0,0 +1,1401 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, AnchorError, BN } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, createAccount, createMint, getAccount, mintTo } from "@solana/spl-token";
+import { expect } from "chai";
+import { CarbonRegistry } from "../target/types/carbon_registry";
+import { CarbonVerification } from "../target/types/carbon_verification";
+
+const YEAR = 365 * 24 * 60 * 60;
+const now = () => Math.floor(Date.now() / 1000);
+
+describe("carbon-verification", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.CarbonVerification as Program<CarbonVerification>;
+  const registryProgram = anchor.workspace.CarbonRegistry as Program<CarbonRegistry>;
+  const connection = provider.connection;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+
+  const pda = (seeds: (Buffer | Uint8Array)[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+  const configPda = pda([Buffer.from("verification_config")]);
+  const programDataPda = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  )[0];
+  const accreditationBodyPda = (bodyAuthority: PublicKey) =>
+    pda([Buffer.from("accreditation_body"), bodyAuthority.toBuffer()]);
+  const verifierPda = (verifierSeed: PublicKey) => pda([Buffer.from("verifier"), verifierSeed.toBuffer()]);
+  const requestPda = (project: PublicKey, requester: PublicKey) =>
+    pda([Buffer.from("verification_request"), project.toBuffer(), requester.toBuffer()]);
+  const resultPda = (request: PublicKey) => pda([Buffer.from("verification_result"), request.toBuffer()]);
+  const challengePda = (result: PublicKey, challenger: PublicKey) =>
+    pda([Buffer.from("challenge"), result.toBuffer(), challenger.toBuffer()]);
+  const stakeVaultPda = (verifier: PublicKey) => pda([Buffer.from("stake_vault"), verifier.toBuffer()]);
+  const feeVaultPda = (request: PublicKey) => pda([Buffer.from("fee_vault"), request.toBuffer()]);
+  const challengePanelPda = pda([Buffer.from("arbitration_panel"), Buffer.from([0])]);
+  const appealPanelPda = pda([Buffer.from("arbitration_panel"), Buffer.from([1])]);
+  const appealPda = (challenge: PublicKey) => pda([Buffer.from("appeal"), challenge.toBuffer()]);
+  const slashRecordPda = (challenge: PublicKey) => pda([Buffer.from("slash"), challenge.toBuffer()]);
+  const APPEAL_WINDOW = 3;
+
+  async function expectError(promise: Promise<unknown>, code: string) {
+    try {
+      await promise;
+    } catch (err) {
+      expect(err).to.be.instanceOf(AnchorError);
+      expect((err as AnchorError).error.errorCode.code).to.equal(code);
+      return;
+    }
+    expect.fail(`expected ${code}`);
+  }
+
+  async function fundedKeypair(): Promise<Keypair> {
+    const keypair = Keypair.generate();
+    const sig = await connection.requestAirdrop(keypair.publicKey, 2 * LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(sig);
+    return keypair;
+  }
+
+  async function registerAccreditationBody(bodyAuthority: PublicKey, name: string): Promise<PublicKey> {
+    const accreditationBody = accreditationBodyPda(bodyAuthority);
+    await program.methods
+      .registerAccreditationBody(bodyAuthority, name)
+      .accounts({
+        accreditationBody,
+        verificationConfig: configPda,
+        authority: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return accreditationBody;
+  }
+
+  async function initializeVerifier(bodyAuthority: Keypair, name: string, authority: Keypair = payer): Promise<PublicKey> {
+    const verifierSeed = Keypair.generate().publicKey;
+    const verifier = verifierPda(verifierSeed);
+    await program.methods
+      .initializeVerifier(verifierSeed, name, { advanced: {} }, [{ forestry: {} }], new BN(now() + YEAR))
+      .accounts({
+        verifier,
+        stakeVault: stakeVaultPda(verifier),
+        accreditationBody: accreditationBodyPda(bodyAuthority.publicKey),
+        accreditationAuthority: bodyAuthority.publicKey,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(authority === payer ? [bodyAuthority] : [bodyAuthority, authority])
+      .rpc();
+    return verifier;
+  }
+
+  // Requests must name a registered project; the tests register theirs in a registry of their own
+  const registryPda = (seeds: (Buffer | Uint8Array)[]) =>
+    PublicKey.findProgramAddressSync(seeds, registryProgram.programId)[0];
+  let projectRegistry: PublicKey;
+  let projectCount = 0;
+
+  const registryRolePda = (role: number) =>
+    registryPda([Buffer.from("role"), projectRegistry.toBuffer(), payer.publicKey.toBuffer(), Buffer.from([role])]);
+
+  async function initializeProjectRegistry() {
+    const registrySeed = Keypair.generate().publicKey;
+    projectRegistry = registryPda([Buffer.from("registry"), registrySeed.toBuffer()]);
+    await registryProgram.methods
+      .initializeRegistry(registrySeed, "Verification Test Registry", "https://registry.example.org")
+      .accounts({
+        registry: projectRegistry,
+        adminRole: registryRolePda(0),
+        authority: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await registryProgram.methods
+      .grantRole(payer.publicKey, { projectApprover: {} })
+      .accounts({
+        roleAssignment: registryRolePda(2),
+        registry: projectRegistry,
+        adminRole: registryRolePda(0),
+        admin: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await registryProgram.methods
+      .initializeCountryStats(Array.from(Buffer.from("KE")))
+      .accounts({
+        countryStats: registryPda([Buffer.from("country_stats"), projectRegistry.toBuffer(), Buffer.from("KE")]),
+        registry: projectRegistry,
+        adminRole: registryRolePda(0),
+        authority: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  async function createProject(projectType: object): Promise<PublicKey> {
+    const sector = Object.keys(projectType)[0];
+    const methodology = registryPda([
+      Buffer.from("methodology"),
+      projectRegistry.toBuffer(),
+      Buffer.from(sector),
+      Buffer.from("1.0"),
+    ]);
+    if (!(await registryProgram.account.methodology.fetchNullable(methodology))) {
+      await registryProgram.methods
+        .addMethodology(sector, "1.0", { cdm: {} }, projectType as any, 7, 10, 2)
+        .accounts({
+          methodology,
+          registry: projectRegistry,
+          adminRole: registryRolePda(0),
+          authority: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    const projectId = `VRF-${projectCount++}`;
+    const projectApplication = registryPda([
+      Buffer.from("project_application"),
+      projectRegistry.toBuffer(),
+      Buffer.from(projectId),
+    ]);
+    const project = registryPda([Buffer.from("project_registry"), projectRegistry.toBuffer(), Buffer.from(projectId)]);
+    const creditingPeriodStart = now() - YEAR;
+    await registryProgram.methods
+      .submitProjectApplication(
+        projectId,
+        new Date(creditingPeriodStart * 1000).getUTCFullYear(),
+        "KE",
+        null,
+        projectType as any,
+        ["https://docs.example.org/pdd.pdf"],
+        new BN(creditingPeriodStart),
+        7
+      )
+      .accounts({
+        projectApplication,
+        methodology,
+        registry: projectRegistry,
+        developer: payer.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await registryProgram.methods
+      .approveProjectApplication()
+      .accounts({
+        projectApplication,
+        methodology,
+        projectRegistry: project,
+        creditMint: registryPda([Buffer.from("credit_mint"), project.toBuffer()]),
+        registry: projectRegistry,
+        countryStats: registryPda([Buffer.from("country_stats"), projectRegistry.toBuffer(), Buffer.from("KE")]),
+        developer: payer.publicKey,
+        approverRole: registryRolePda(2),
+        authority: payer.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+    return project;
+  }
+
+  const submitRequest = async (
+    verifier: PublicKey,
+    accreditationBody: PublicKey,
+    requester: Keypair,
+    projectType: object = { forestry: {} },
+    estimatedCredits = 1000,
+    project: PublicKey | null = null,
+    coVerifiers: PublicKey[] = [],
+    requiredSubmissions = 1,
+    aggregation: object = { minimum: {} },
+    fee: { amount: number; mint: PublicKey; requesterTokenAccount: PublicKey } | null = null
+  ) => {
+    project = project ?? (await createProject(projectType));
+    return program.methods
+      .submitVerificationRequest(
+        project,
+        { initial: {} },
+        "https://docs.example.org/pdd.pdf",
+        new BN(estimatedCredits),
+        coVerifiers,
+        requiredSubmissions,
+        aggregation as any,
+        new BN(fee?.amount ?? 0)
+      )
+      .accounts({
+        verificationRequest: requestPda(project, requester.publicKey),
+        project,
+        verifier,
+        accreditationBody,
+        verificationConfig: configPda,
+        feeMint: fee?.mint ?? null,
+        feeVault: fee ? feeVaultPda(requestPda(project, requester.publicKey)) : null,
+        requesterTokenAccount: fee?.requesterTokenAccount ?? null,
+        requester: requester.publicKey,
+        tokenProgram: fee ? TOKEN_PROGRAM_ID : null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([requester])
+      .rpc();
+  };
+
+  const depositStake = (verifier: PublicKey, amount: number, authority: Keypair = payer) =>
+    program.methods
+      .depositStake(new BN(amount))
+      .accounts({
+        verifier,
+        stakeVault: stakeVaultPda(verifier),
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(authority === payer ? [] : [authority])
+      .rpc();
+
+  // Co-verifiers behind a result, passed as remaining accounts when a challenge is filed or expires
+  const coVerifierAccounts = async (verificationResult: PublicKey) => {
+    const { verifiers } = await program.account.verificationResult.fetch(verificationResult);
+    return verifiers.slice(1).map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }));
+  };
+
+  const challengeVerification = async (verificationResult: PublicKey, challenger: Keypair) => {
+    const challenge = challengePda(verificationResult, challenger.publicKey);
+    const { verifier } = await program.account.verificationResult.fetch(verificationResult);
+    await program.methods
+      .challengeVerification("baseline inflated", "https://evidence.example.org/report.pdf")
+      .accounts({
+        verificationResult,
+        challenge,
+        verificationConfig: configPda,
+        arbitrationPanel: challengePanelPda,
+        verifier,
+        challenger: challenger.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(await coVerifierAccounts(verificationResult))
+      .signers([challenger])
+      .rpc();
+    return challenge;
+  };
+
+  const castVote = (
+    challenge: PublicKey,
+    verificationResult: PublicKey,
+    verifier: PublicKey,
+    member: Keypair,
+    vote: object
+  ) =>
+    program.methods
+      .castChallengeVote(vote as any, "panel finding")
+      .accounts({
+        challenge,
+        challengeVote: pda([Buffer.from("challenge_vote"), challenge.toBuffer(), member.publicKey.toBuffer()]),
+        verificationResult,
+        verifier,
+        verificationConfig: configPda,
+        member: member.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(member === payer ? [] : [member]);
+
+  const fileAppeal = (challenge: PublicKey, verificationResult: PublicKey, verifier: PublicKey, appellant: Keypair) =>
+    program.methods
+      .fileAppeal("panel misread the monitoring data", "https://evidence.example.org/rebuttal.pdf")
+      .accounts({
+        challenge,
+        appeal: appealPda(challenge),
+        verificationResult,
+        verifier,
+        verificationConfig: configPda,
+        arbitrationPanel: appealPanelPda,
+        appellant: appellant.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(appellant === payer ? [] : [appellant]);
+
+  const castAppealVote = (
+    challenge: PublicKey,
+    verificationResult: PublicKey,
+    verifier: PublicKey,
+    member: Keypair,
+    vote: object
+  ) =>
+    program.methods
+      .castAppealVote(vote as any, "appeal finding")
+      .accounts({
+        appeal: appealPda(challenge),
+        challenge,
+        appealVote: pda([Buffer.from("appeal_vote"), appealPda(challenge).toBuffer(), member.publicKey.toBuffer()]),
+        verificationResult,
+        verifier,
+        member: member.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([member]);
+
+  async function settleChallenge(
+    challenge: PublicKey,
+    verificationResult: PublicKey,
+    verifier: PublicKey,
+    {
+      appeal = null as PublicKey | null,
+      slashRecord = null as PublicKey | null,
+      bondRecipient = payer.publicKey,
+      requesterTokenAccount = null as PublicKey | null,
+      coVerifiers = [] as PublicKey[],
+    } = {}
+  ) {
+    const { request } = await program.account.verificationResult.fetch(verificationResult);
+    return program.methods
+      .settleChallenge()
+      .accounts({
+        challenge,
+        appeal,
+        verificationResult,
+        verificationRequest: request,
+        feeVault: requesterTokenAccount ? feeVaultPda(request) : null,
+        requesterTokenAccount,
+        requester: requesterTokenAccount ? requester.publicKey : null,
+        verifier,
+        stakeVault: stakeVaultPda(verifier),
+        verificationConfig: configPda,
+        challenger: outsider.publicKey,
+        verifierAuthority: payer.publicKey,
+        bondRecipient,
+        slashRecord,
+        payer: payer.publicKey,
+        tokenProgram: requesterTokenAccount ? TOKEN_PROGRAM_ID : null,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(
+        coVerifiers.flatMap((coVerifier) => [
+          { pubkey: coVerifier, isWritable: true, isSigner: false },
+          { pubkey: stakeVaultPda(coVerifier), isWritable: true, isSigner: false },
+        ])
+      )
+      .rpc();
+  }
+
+  // Waits for the validator clock to pass a timestamp
+  async function waitUntil(timestamp: number) {
+    while ((await connection.getBlockTime(await connection.getSlot())) <= timestamp) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+  }
+
+  async function waitForAppealDeadline(challenge: PublicKey) {
+    const { appealDeadline } = await program.account.verificationChallenge.fetch(challenge);
+    await waitUntil(appealDeadline.toNumber());
+  }
+
+  const acceptRequest = (
+    verificationRequest: PublicKey,
+    verifier: PublicKey,
+    feeQuote: number | null = null,
+    deadline: number | null = null,
+    verifierAuthority: Keypair = payer
+  ) =>
+    program.methods
+      .acceptRequest(feeQuote === null ? null : new BN(feeQuote), deadline === null ? null : new BN(deadline))
+      .accounts({
+        verificationRequest,
+        verifier,
+        accreditationBody,
+        verificationConfig: configPda,
+        verifierAuthority: verifierAuthority.publicKey,
+      })
+      .signers(verifierAuthority === payer ? [] : [verifierAuthority])
+      .rpc();
+
+  const conductVerification = (
+    verificationRequest: PublicKey,
+    verifier: PublicKey,
+    verifiedCredits = 1000,
+    complianceScore = 85,
+    verificationResult: PublicKey | null = resultPda(verificationRequest),
+    verifierTokenAccount: PublicKey | null = null,
+    verifierAuthority: Keypair = payer
+  ) =>
+    program.methods
+      .conductVerification(new BN(verifiedCredits), "site visit completed", complianceScore)
+      .accounts({
+        verificationRequest,
+        verificationResult,
+        verifier,
+        accreditationBody,
+        verificationConfig: configPda,
+        feeVault: verifierTokenAccount ? feeVaultPda(verificationRequest) : null,
+        verifierTokenAccount,
+        requester: verifierTokenAccount ? requester.publicKey : null,
+        verifierAuthority: verifierAuthority.publicKey,
+        tokenProgram: verifierTokenAccount ? TOKEN_PROGRAM_ID : null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(verifierAuthority === payer ? [] : [verifierAuthority])
+      .rpc();
+
+  // Runs a verification through to completion and returns the verification result
+  async function completeVerification(verifier: PublicKey, requester: Keypair): Promise<PublicKey> {
+    const project = await createProject({ forestry: {} });
+    const verificationRequest = requestPda(project, requester.publicKey);
+    await submitRequest(verifier, accreditationBody, requester, { forestry: {} }, 1000, project);
+    await acceptRequest(verificationRequest, verifier);
+    await conductVerification(verificationRequest, verifier);
+    return resultPda(verificationRequest);
+  }
+
+  const setAccreditation = (verifier: PublicKey, bodyAuthority: Keypair, status: object, accreditedUntil: number) =>
+    program.methods
+      .setVerifierAccreditation(status as any, new BN(accreditedUntil))
+      .accounts({
+        verifier,
+        accreditationBody: accreditationBodyPda(bodyAuthority.publicKey),
+        accreditationAuthority: bodyAuthority.publicKey,
+      })
+      .signers([bodyAuthority])
+      .rpc();
+
+  let bodyAuthority: Keypair;
+  let accreditationBody: PublicKey;
+  let requester: Keypair;
+  let outsider: Keypair;
+  let arbiters: Keypair[];
+  let appealArbiters: Keypair[];
+
+  async function configurePanel(panel: PublicKey, tier: object, members: Keypair[], votingPeriod = 7 * 24 * 60 * 60) {
+    const panelArgs = [members.map((member) => member.publicKey), 2, new BN(votingPeriod)] as const;
+    if (await program.account.arbitrationPanel.fetchNullable(panel)) {
+      await program.methods
+        .updateArbitrationPanel(...panelArgs)
+        .accounts({ arbitrationPanel: panel, verificationConfig: configPda, authority: payer.publicKey })
+        .rpc();
+    } else {
+      await program.methods
+        .initializeArbitrationPanel(tier as any, ...panelArgs)
+        .accounts({
+          arbitrationPanel: panel,
+          verificationConfig: configPda,
+          authority: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+  }
+
+  before(async () => {
+    if (!(await program.account.verificationConfig.fetchNullable(configPda))) {
+      await program.methods
+        .initializeVerificationConfig()
+        .accounts({
+          verificationConfig: configPda,
+          program: program.programId,
+          programData: programDataPda,
+          authority: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+    await initializeProjectRegistry();
+    bodyAuthority = Keypair.generate();
+    accreditationBody = await registerAccreditationBody(bodyAuthority.publicKey, "UKAS");
+    requester = await fundedKeypair();
+    outsider = await fundedKeypair();
+
+    arbiters = [await fundedKeypair(), await fundedKeypair(), await fundedKeypair()];
+    appealArbiters = [await fundedKeypair(), await fundedKeypair(), await fundedKeypair()];
+    await configurePanel(challengePanelPda, { challenge: {} }, arbiters);
+    await configurePanel(appealPanelPda, { appeal: {} }, appealArbiters);
+
+    // A short appeal window lets the tests settle without waiting days
+    await program.methods
+      .setChallengeParams(new BN(LAMPORTS_PER_SOL / 10), new BN(LAMPORTS_PER_SOL / 2), new BN(APPEAL_WINDOW), null)
+      .accounts({ verificationConfig: configPda, authority: payer.publicKey })
+      .rpc();
+  });
+
+  it("rejects accreditation bodies registered by anyone but the config authority", async () => {
+    const bodyKey = Keypair.generate().publicKey;
+    await expectError(
+      program.methods
+        .registerAccreditationBody(bodyKey, "Self Accredited")
+        .accounts({
+          accreditationBody: accreditationBodyPda(bodyKey),
+          verificationConfig: configPda,
+          authority: outsider.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc(),
+      "UnauthorizedAuthority"
+    );
+  });
+
+  it("requires the accreditation body to co-sign verifier creation", async () => {
+    const verifierSeed = Keypair.generate().publicKey;
+    await expectError(
+      program.methods
+        .initializeVerifier(verifierSeed, "Unaccredited", { basic: {} }, [{ forestry: {} }], new BN(now() + YEAR))
+        .accounts({
+          verifier: verifierPda(verifierSeed),
+          stakeVault: stakeVaultPda(verifierPda(verifierSeed)),
+          accreditationBody,
+          accreditationAuthority: outsider.publicKey,
+          authority: payer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc(),
+      "UnauthorizedAccreditationBody"
+    );
+  });
+
+  it("records the accreditation on the verifier", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Accredited Verifier");
+    const state = await program.account.verifier.fetch(verifier);
+    expect(state.accreditationBody.toBase58()).to.equal(accreditationBody.toBase58());
+    expect(state.accreditedSectors).to.deep.equal([{ forestry: {} }]);
+    expect(state.accreditationStatus).to.deep.equal({ active: {} });
+
+    const body = await program.account.accreditationBody.fetch(accreditationBody);
+    expect(body.totalVerifiersAccredited.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("rejects requests to suspended verifiers until reinstated", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Suspended Verifier");
+    await setAccreditation(verifier, bodyAuthority, { suspended: {} }, now() + YEAR);
+    await expectError(submitRequest(verifier, accreditationBody, requester), "VerifierNotAccredited");
+
+    await setAccreditation(verifier, bodyAuthority, { active: {} }, now() + YEAR);
+    await submitRequest(verifier, accreditationBody, requester);
+  });
+
+  it("keeps revoked verifiers revoked", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Revoked Verifier");
+    await setAccreditation(verifier, bodyAuthority, { revoked: {} }, now());
+    await expectError(setAccreditation(verifier, bodyAuthority, { active: {} }, now() + YEAR), "AccreditationRevoked");
+    await expectError(submitRequest(verifier, accreditationBody, requester), "VerifierNotAccredited");
+  });
+
+  it("rejects requests once the accreditation body is deactivated", async () => {
+    const otherAuthority = Keypair.generate();
+    const otherBody = await registerAccreditationBody(otherAuthority.publicKey, "Deactivated Body");
+    const verifier = await initializeVerifier(otherAuthority, "Orphaned Verifier");
+    await program.methods
+      .setAccreditationBodyStatus(false)
+      .accounts({ accreditationBody: otherBody, verificationConfig: configPda, authority: payer.publicKey })
+      .rpc();
+
+    await expectError(submitRequest(verifier, otherBody, requester), "AccreditationBodyInactive");
+  });
+
+  it("rejects an accreditation body the verifier does not belong to", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Mismatched Verifier");
+    const otherBody = await registerAccreditationBody(Keypair.generate().publicKey, "Unrelated Body");
+    await expectError(submitRequest(verifier, otherBody, outsider), "UnauthorizedAccreditationBody");
+  });
+
+  it("rejects projects outside the verifier's accredited sectors", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Forestry Verifier");
+    await expectError(
+      submitRequest(verifier, accreditationBody, requester, { methane: {} }),
+      "SectorNotAccredited"
+    );
+  });
+
+  it("reads the project type from the project account", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Project Type Verifier");
+    await expectError(
+      submitRequest(verifier, accreditationBody, requester, undefined, 1000, Keypair.generate().publicKey),
+      "UnsupportedProject"
+    );
+
+    const project = await createProject({ forestry: {} });
+    await submitRequest(verifier, accreditationBody, requester, undefined, 1000, project);
+    const request = await program.account.verificationRequest.fetch(requestPda(project, requester.publicKey));
+    expect(request.projectType).to.deep.equal({ forestry: {} });
+  });
+
+  it("rejects projects above the certification level's credit limit", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Advanced Verifier");
+    const { creditLimits } = await program.account.verificationConfig.fetch(configPda);
+    const advancedLimit = creditLimits[2].toNumber();
+    await submitRequest(verifier, accreditationBody, requester, { forestry: {} }, advancedLimit);
+    await expectError(
+      submitRequest(verifier, accreditationBody, requester, { forestry: {} }, advancedLimit + 1),
+      "ExceedsCertificationLimit"
+    );
+  });
+
+  it("rejects credit limits that shrink with certification level", async () => {
+    await expectError(
+      program.methods
+        .setCertificationLimits([new BN(1000), new BN(500), new BN(2000), new BN(3000)])
+        .accounts({ verificationConfig: configPda, authority: payer.publicKey })
+        .rpc(),
+      "InvalidCertificationLimits"
+    );
+  });
+
+  it("holds unbonding stake until the unbonding period has passed", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Unbonding Verifier");
+    await depositStake(verifier, LAMPORTS_PER_SOL / 2);
+    await program.methods
+      .requestUnbonding(new BN(LAMPORTS_PER_SOL / 4))
+      .accounts({ verifier, verificationConfig: configPda, authority: payer.publicKey })
+      .rpc();
+
+    const state = await program.account.verifier.fetch(verifier);
+    expect(state.stake.toNumber()).to.equal(LAMPORTS_PER_SOL / 4);
+    expect(state.unbondingAmount.toNumber()).to.equal(LAMPORTS_PER_SOL / 4);
+    await expectError(
+      program.methods
+        .withdrawUnbonded()
+        .accounts({ verifier, stakeVault: stakeVaultPda(verifier), authority: payer.publicKey })
+        .rpc(),
+      "UnbondingPeriodActive"
+    );
+  });
+
+  it("restarts the unbonding period for all unbonding stake on each request", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Pooled Unbonding Verifier");
+    await depositStake(verifier, LAMPORTS_PER_SOL / 2);
+    const requestUnbonding = (amount: number) =>
+      program.methods
+        .requestUnbonding(new BN(amount))
+        .accounts({ verifier, verificationConfig: configPda, authority: payer.publicKey })
+        .rpc();
+    const setUnbondingPeriod = (unbondingPeriod: number) =>
+      program.methods
+        .setStakingParams(new BN(0), 1000, 5000, new BN(unbondingPeriod))
+        .accounts({ verificationConfig: configPda, authority: payer.publicKey })
+        .rpc();
+
+    await setUnbondingPeriod(0);
+    try {
+      await requestUnbonding(LAMPORTS_PER_SOL / 4);
+    } finally {
+      await setUnbondingPeriod(14 * 24 * 60 * 60);
+    }
+    const first = await program.account.verifier.fetch(verifier);
+    await requestUnbonding(LAMPORTS_PER_SOL / 4);
+
+    // The first request was already withdrawable, but the second holds it for a full period again
+    const state = await program.account.verifier.fetch(verifier);
+    expect(state.unbondingAmount.toNumber()).to.equal(LAMPORTS_PER_SOL / 2);
+    expect(state.unbondingAvailableAt.toNumber()).to.be.at.least(
+      first.unbondingAvailableAt.toNumber() + 14 * 24 * 60 * 60
+    );
+    await expectError(
+      program.methods
+        .withdrawUnbonded()
+        .accounts({ verifier, stakeVault: stakeVaultPda(verifier), authority: payer.publicKey })
+        .rpc(),
+      "UnbondingPeriodActive"
+    );
+  });
+
+  it("keeps stake bonded until challenges against the verifier are settled", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Challenged Unbonding Verifier");
+    await depositStake(verifier, LAMPORTS_PER_SOL / 2);
+    const verificationResult = await completeVerification(verifier, requester);
+    const challenge = await challengeVerification(verificationResult, outsider);
+    expect((await program.account.verifier.fetch(verifier)).openChallenges).to.equal(1);
+
+    const setUnbondingPeriod = (unbondingPeriod: number) =>
+      program.methods
+        .setStakingParams(new BN(0), 1000, 5000, new BN(unbondingPeriod))
+        .accounts({ verificationConfig: configPda, authority: payer.publicKey })
+        .rpc();
+    const withdrawUnbonded = () =>
+      program.methods
+        .withdrawUnbonded()
+        .accounts({ verifier, stakeVault: stakeVaultPda(verifier), authority: payer.publicKey })
+        .rpc();
+    await setUnbondingPeriod(0);
+    try {
+      await program.methods
+        .requestUnbonding(new BN(LAMPORTS_PER_SOL / 2))
+        .accounts({ verifier, verificationConfig: configPda, authority: payer.publicKey })
+        .rpc();
+      await expectError(withdrawUnbonded(), "ChallengesPending");
+    } finally {
+      await setUnbondingPeriod(14 * 24 * 60 * 60);
+    }
+
+    await castVote(challenge, verificationResult, verifier, arbiters[0], { rejected: {} }).rpc();
+    await castVote(challenge, verificationResult, verifier, arbiters[1], { rejected: {} }).rpc();
+    await waitForAppealDeadline(challenge);
+    await settleChallenge(challenge, verificationResult, verifier);
+    expect((await program.account.verifier.fetch(verifier)).openChallenges).to.equal(0);
+
+    await withdrawUnbonded();
+    const state = await program.account.verifier.fetch(verifier);
+    expect(state.unbondingAmount.toNumber()).to.equal(0);
+    expect(state.unbondingAvailableAt).to.equal(null);
+  });
+
+  it("slashes the verifier when the panel upholds a challenge", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Slashed Verifier");
+    await depositStake(verifier, LAMPORTS_PER_SOL);
+    const verificationResult = await completeVerification(verifier, requester);
+
+    const challenge = await challengeVerification(verificationResult, outsider);
+
+    const config = await program.account.verificationConfig.fetch(configPda);
+    const slashed = (LAMPORTS_PER_SOL * config.slashBps) / 10000;
+    const challengerAmount = (slashed * config.challengerShareBps) / 10000;
+    const challengerBefore = await connection.getBalance(outsider.publicKey);
+    const slashRecord = slashRecordPda(challenge);
+
+    await castVote(challenge, verificationResult, verifier, arbiters[0], { upheld: {} }).rpc();
+    await castVote(challenge, verificationResult, verifier, arbiters[1], { upheld: {} }).rpc();
+    let result = await program.account.verificationResult.fetch(verificationResult);
+    expect(result.isValid).to.equal(false);
+    expect(result.underChallenge).to.equal(true);
+
+    await expectError(settleChallenge(challenge, verificationResult, verifier, { slashRecord }), "AppealWindowOpen");
+    await waitForAppealDeadline(challenge);
+    await settleChallenge(challenge, verificationResult, verifier, { slashRecord });
+
+    const state = await program.account.verifier.fetch(verifier);
+    expect(state.stake.toNumber()).to.equal(LAMPORTS_PER_SOL - slashed);
+    expect(state.totalSlashed.toNumber()).to.equal(slashed);
+    expect(await connection.getBalance(outsider.publicKey)).to.equal(
+      challengerBefore + config.challengeBond.toNumber() + challengerAmount
+    );
+    result = await program.account.verificationResult.fetch(verificationResult);
+    expect(result.isValid).to.equal(false);
+    expect(result.underChallenge).to.equal(false);
+
+    const record = await program.account.slashRecord.fetch(slashRecord);
+    expect(record.compensationAmount.toNumber()).to.equal(slashed - challengerAmount);
+
+    const buyer = Keypair.generate().publicKey;
+    await program.methods
+      .distributeCompensation(record.compensationAmount)
+      .accounts({ slashRecord, verificationConfig: configPda, recipient: buyer, authority: payer.publicKey })
+      .rpc();
+    expect(await connection.getBalance(buyer)).to.equal(record.compensationAmount.toNumber());
+  });
+
+  it("keeps a challenged verification valid and forfeits the bond of a rejected challenge", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Challenged Verifier");
+    const verificationResult = await completeVerification(verifier, requester);
+    const challenge = await challengeVerification(verificationResult, outsider);
+
+    let result = await program.account.verificationResult.fetch(verificationResult);
+    expect(result.isValid).to.equal(true);
+    expect(result.underChallenge).to.equal(true);
+    const { bond } = await program.account.verificationChallenge.fetch(challenge);
+    const challengeBalance = await connection.getBalance(challenge);
+
+    await castVote(challenge, verificationResult, verifier, arbiters[0], { rejected: {} }).rpc();
+    await castVote(challenge, verificationResult, verifier, arbiters[1], { rejected: {} }).rpc();
+    await waitForAppealDeadline(challenge);
+    await expectError(
+      settleChallenge(challenge, verificationResult, verifier, { bondRecipient: outsider.publicKey }),
+      "InvalidBondRecipient"
+    );
+    await settleChallenge(challenge, verificationResult, verifier);
+
+    result = await program.account.verificationResult.fetch(verificationResult);
+    expect(result.isValid).to.equal(true);
+    expect(result.underChallenge).to.equal(false);
+    expect(await connection.getBalance(challenge)).to.equal(challengeBalance - bond.toNumber());
+  });
+
+  it("only resolves once a side reaches quorum", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Split Panel Verifier");
+    const verificationResult = await completeVerification(verifier, requester);
+    const challenge = await challengeVerification(verificationResult, outsider);
+
+    await castVote(challenge, verificationResult, verifier, arbiters[0], { upheld: {} }).rpc();
+    await castVote(challenge, verificationResult, verifier, arbiters[1], { rejected: {} }).rpc();
+    let state = await program.account.verificationChallenge.fetch(challenge);
+    expect(state.status).to.deep.equal({ open: {} });
+
+    await castVote(challenge, verificationResult, verifier, arbiters[2], { rejected: {} }).rpc();
+    state = await program.account.verificationChallenge.fetch(challenge);
+    expect(state.status).to.deep.equal({ rejected: {} });
+    expect(state.votesUpheld).to.equal(1);
+    expect(state.votesRejected).to.equal(2);
+  });
+
+  it("excludes the verifier and non-members from voting", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Conflicted Verifier");
+    const verificationResult = await completeVerification(verifier, requester);
+    const challenge = await challengeVerification(verificationResult, outsider);
+
+    await expectError(
+      castVote(challenge, verificationResult, verifier, payer, { rejected: {} }).rpc(),
+      "ConflictOfInterest"
+    );
+    await expectError(
+      castVote(challenge, verificationResult, verifier, outsider, { upheld: {} }).rpc(),
+      "ConflictOfInterest"
+    );
+    await expectError(
+      castVote(challenge, verificationResult, verifier, requester, { upheld: {} }).rpc(),
+      "NotPanelMember"
+    );
+  });
+
+  it("counts votes against the panel as it stood when the challenge was filed", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Snapshot Verifier");
+    const verificationResult = await completeVerification(verifier, requester);
+    const challenge = await challengeVerification(verificationResult, outsider);
+
+    const replacements = [await fundedKeypair(), await fundedKeypair(), await fundedKeypair()];
+    await configurePanel(challengePanelPda, { challenge: {} }, replacements);
+    try {
+      await expectError(
+        castVote(challenge, verificationResult, verifier, replacements[0], { upheld: {} }).rpc(),
+        "NotPanelMember"
+      );
+      await castVote(challenge, verificationResult, verifier, arbiters[0], { upheld: {} }).rpc();
+      await castVote(challenge, verificationResult, verifier, arbiters[1], { upheld: {} }).rpc();
+    } finally {
+      await configurePanel(challengePanelPda, { challenge: {} }, arbiters);
+    }
+
+    const state = await program.account.verificationChallenge.fetch(challenge);
+    expect(state.status).to.deep.equal({ upheld: {} });
+    expect(state.panelMembers.map((key) => key.toBase58())).to.deep.equal(
+      arbiters.map((arbiter) => arbiter.publicKey.toBase58())
+    );
+    expect(state.quorum).to.equal(2);
+  });
+
+  it("expires a challenge that reaches no quorum and refunds the bond", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Expiring Challenge Verifier");
+    const verificationResult = await completeVerification(verifier, requester);
+    // The voting period is fixed at filing, so the panel can be restored straight away
+    await configurePanel(challengePanelPda, { challenge: {} }, arbiters, 3);
+    const challenge = await challengeVerification(verificationResult, outsider);
+    await configurePanel(challengePanelPda, { challenge: {} }, arbiters);
+    const expireChallenge = () =>
+      program.methods
+        .expireChallenge()
+        .accounts({ challenge, verificationResult, verifier, challenger: outsider.publicKey })
+        .rpc();
+
+    await castVote(challenge, verificationResult, verifier, arbiters[0], { upheld: {} }).rpc();
+    await expectError(expireChallenge(), "VotingStillOpen");
+
+    const { votingEndsAt, bond } = await program.account.verificationChallenge.fetch(challenge);
+    await waitUntil(votingEndsAt.toNumber());
+    await expectError(
+      castVote(challenge, verificationResult, verifier, arbiters[1], { upheld: {} }).rpc(),
+      "VotingClosed"
+    );
+    const challengerBefore = await connection.getBalance(outsider.publicKey);
+    await expireChallenge();
+
+    const state = await program.account.verificationChallenge.fetch(challenge);
+    expect(state.status).to.deep.equal({ expired: {} });
+    expect(state.settled).to.equal(true);
+    expect(await connection.getBalance(outsider.publicKey)).to.equal(challengerBefore + bond.toNumber());
+    const result = await program.account.verificationResult.fetch(verificationResult);
+    expect(result.isValid).to.equal(true);
+    expect(result.underChallenge).to.equal(false);
+    await expectError(expireChallenge(), "ChallengeNotOpen");
+  });
+
+  it("lets the verifier appeal an upheld challenge to the appeal panel", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Appealing Verifier");
+    await depositStake(verifier, LAMPORTS_PER_SOL);
+    const verificationResult = await completeVerification(verifier, requester);
+    const challenge = await challengeVerification(verificationResult, outsider);
+
+    await castVote(challenge, verificationResult, verifier, arbiters[0], { upheld: {} }).rpc();
+    await castVote(challenge, verificationResult, verifier, arbiters[1], { upheld: {} }).rpc();
+    await expectError(fileAppeal(challenge, verificationResult, verifier, requester).rpc(), "NotChallengeParty");
+    await fileAppeal(challenge, verificationResult, verifier, payer).rpc();
+    const appeal = appealPda(challenge);
+    await expectError(
+      settleChallenge(challenge, verificationResult, verifier, { appeal }),
+      "AppealPending"
+    );
+
+    await expectError(
+      castAppealVote(challenge, verificationResult, verifier, outsider, { upheld: {} }).rpc(),
+      "ConflictOfInterest"
+    );
+    await castAppealVote(challenge, verificationResult, verifier, appealArbiters[0], { rejected: {} }).rpc();
+    await castAppealVote(challenge, verificationResult, verifier, appealArbiters[1], { rejected: {} }).rpc();
+
+    const decided = await program.account.challengeAppeal.fetch(appeal);
+    expect(decided.status).to.deep.equal({ decided: {} });
+    expect(decided.ruling).to.deep.equal({ rejected: {} });
+    let result = await program.account.verificationResult.fetch(verificationResult);
+    expect(result.isValid).to.equal(true);
+
+    // The overturned ruling refunds the appeal bond and forfeits the challenger's bond without a slash
+    const appealBalance = await connection.getBalance(appeal);
+    await settleChallenge(challenge, verificationResult, verifier, { appeal });
+    expect(await connection.getBalance(appeal)).to.equal(appealBalance - decided.bond.toNumber());
+    const state = await program.account.verifier.fetch(verifier);
+    expect(state.stake.toNumber()).to.equal(LAMPORTS_PER_SOL);
+    result = await program.account.verificationResult.fetch(verificationResult);
+    expect(result.underChallenge).to.equal(false);
+    await expectError(
+      settleChallenge(challenge, verificationResult, verifier, { appeal }),
+      "ChallengeAlreadySettled"
+    );
+  });
+
+  it("rejects an appeal to a panel that shares members with the first-instance panel", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Overlapping Panel Verifier");
+    const verificationResult = await completeVerification(verifier, requester);
+    const challenge = await challengeVerification(verificationResult, outsider);
+    await castVote(challenge, verificationResult, verifier, arbiters[0], { upheld: {} }).rpc();
+    await castVote(challenge, verificationResult, verifier, arbiters[1], { upheld: {} }).rpc();
+
+    await configurePanel(appealPanelPda, { appeal: {} }, [arbiters[2], ...appealArbiters.slice(1)]);
+    try {
+      await expectError(fileAppeal(challenge, verificationResult, verifier, payer).rpc(), "AppealPanelOverlap");
+    } finally {
+      await configurePanel(appealPanelPda, { appeal: {} }, appealArbiters);
+    }
+    await fileAppeal(challenge, verificationResult, verifier, payer).rpc();
+    const appeal = await program.account.challengeAppeal.fetch(appealPda(challenge));
+    expect(appeal.panelMembers.map((key) => key.toBase58())).to.deep.equal(
+      appealArbiters.map((arbiter) => arbiter.publicKey.toBase58())
+    );
+  });
+
+  it("closes the appeal window at the deadline", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Late Appeal Verifier");
+    const verificationResult = await completeVerification(verifier, requester);
+    const challenge = await challengeVerification(verificationResult, outsider);
+
+    await castVote(challenge, verificationResult, verifier, arbiters[0], { rejected: {} }).rpc();
+    await castVote(challenge, verificationResult, verifier, arbiters[1], { rejected: {} }).rpc();
+    await waitForAppealDeadline(challenge);
+    await expectError(fileAppeal(challenge, verificationResult, verifier, outsider).rpc(), "AppealWindowClosed");
+  });
+
+  it("completes a multi-verifier request once the threshold is met", async () => {
+    const thirdAuthority = await fundedKeypair();
+    const verifiers = [
+      await initializeVerifier(bodyAuthority, "Lead Verifier"),
+      await initializeVerifier(bodyAuthority, "Second Verifier"),
+      await initializeVerifier(bodyAuthority, "Third Verifier", thirdAuthority),
+    ];
+    const project = await createProject({ forestry: {} });
+    const verificationRequest = requestPda(project, requester.publicKey);
+    const verificationResult = resultPda(verificationRequest);
+    await submitRequest(
+      verifiers[0],
+      accreditationBody,
+      requester,
+      { forestry: {} },
+      1000,
+      project,
+      verifiers.slice(1),
+      2,
+      { median: {} }
+    );
+
+    const outsiderVerifier = await initializeVerifier(bodyAuthority, "Unnamed Verifier");
+    await expectError(acceptRequest(verificationRequest, outsiderVerifier), "NotRequestVerifier");
+    await acceptRequest(verificationRequest, verifiers[0]);
+    await acceptRequest(verificationRequest, verifiers[1]);
+    await acceptRequest(verificationRequest, verifiers[2], null, null, thirdAuthority);
+    await expectError(conductVerification(verificationRequest, verifiers[1], 900, 80), "ConsensusNotReached");
+
+    await conductVerification(verificationRequest, verifiers[1], 900, 80, null);
+    await expectError(conductVerification(verificationRequest, verifiers[1], 900, 80, null), "AlreadySubmitted");
+    let request = await program.account.verificationRequest.fetch(verificationRequest);
+    expect(request.status).to.deep.equal({ inProgress: {} });
+    expect(await program.account.verificationResult.fetchNullable(verificationResult)).to.equal(null);
+
+    await conductVerification(verificationRequest, verifiers[2], 1200, 90, resultPda(verificationRequest), null, thirdAuthority);
+    request = await program.account.verificationRequest.fetch(verificationRequest);
+    expect(request.status).to.deep.equal({ completed: {} });
+
+    // Lower median of two submissions is the smaller one
+    const result = await program.account.verificationResult.fetch(verificationResult);
+    expect(result.verifiedCredits.toNumber()).to.equal(900);
+    expect(result.complianceScore).to.equal(80);
+    expect(result.isValid).to.equal(true);
+    expect(result.verifier.equals(verifiers[1])).to.equal(true);
+    expect(result.verifiers.map((key) => key.toBase58())).to.deep.equal(
+      [verifiers[1], verifiers[2]].map((key) => key.toBase58())
+    );
+    expect(result.verifierAuthorities.map((key) => key.toBase58())).to.deep.equal(
+      [payer.publicKey, thirdAuthority.publicKey].map((key) => key.toBase58())
+    );
+  });
+
+  it("rejects a second submission from verifiers under the same authority", async () => {
+    const verifiers = [
+      await initializeVerifier(bodyAuthority, "Sister Lead"),
+      await initializeVerifier(bodyAuthority, "Sister Verifier"),
+    ];
+    const project = await createProject({ forestry: {} });
+    const verificationRequest = requestPda(project, requester.publicKey);
+    await submitRequest(verifiers[0], accreditationBody, requester, { forestry: {} }, 1000, project, [verifiers[1]], 2);
+    await acceptRequest(verificationRequest, verifiers[0]);
+    await acceptRequest(verificationRequest, verifiers[1]);
+
+    await conductVerification(verificationRequest, verifiers[0], 1000, 85, null);
+    await expectError(conductVerification(verificationRequest, verifiers[1]), "DuplicateVerifierAuthority");
+  });
+
+  it("slashes every verifier behind an upheld result and keeps all of them off the panel", async () => {
+    // The co-verifier answers to a challenge panel member
+    const coAuthority = arbiters[2];
+    const lead = await initializeVerifier(bodyAuthority, "Slashed Lead");
+    const coVerifier = await initializeVerifier(bodyAuthority, "Slashed Co-Verifier", coAuthority);
+    const stake = LAMPORTS_PER_SOL / 2;
+    await depositStake(lead, stake);
+    await depositStake(coVerifier, stake, coAuthority);
+
+    const project = await createProject({ forestry: {} });
+    const verificationRequest = requestPda(project, requester.publicKey);
+    const verificationResult = resultPda(verificationRequest);
+    await submitRequest(lead, accreditationBody, requester, { forestry: {} }, 1000, project, [coVerifier], 2);
+    await acceptRequest(verificationRequest, lead);
+    await acceptRequest(verificationRequest, coVerifier, null, null, coAuthority);
+    await conductVerification(verificationRequest, lead, 1000, 85, null);
+    await conductVerification(verificationRequest, coVerifier, 1000, 85, verificationResult, null, coAuthority);
+
+    const challenge = await challengeVerification(verificationResult, outsider);
+    await expectError(
+      castVote(challenge, verificationResult, lead, coAuthority, { rejected: {} }).rpc(),
+      "ConflictOfInterest"
+    );
+    await castVote(challenge, verificationResult, lead, arbiters[0], { upheld: {} }).rpc();
+    await castVote(challenge, verificationResult, lead, arbiters[1], { upheld: {} }).rpc();
+    await waitForAppealDeadline(challenge);
+
+    const slashRecord = slashRecordPda(challenge);
+    await expectError(settleChallenge(challenge, verificationResult, lead, { slashRecord }), "VerifierAccountsRequired");
+    await settleChallenge(challenge, verificationResult, lead, { slashRecord, coVerifiers: [coVerifier] });
+
+    const config = await program.account.verificationConfig.fetch(configPda);
+    const slashed = (stake * config.slashBps) / 10000;
+    for (const verifier of [lead, coVerifier]) {
+      const state = await program.account.verifier.fetch(verifier);
+      expect(state.stake.toNumber()).to.equal(stake - slashed);
+      expect(state.totalSlashed.toNumber()).to.equal(slashed);
+      expect(state.openChallenges).to.equal(0);
+    }
+    const record = await program.account.slashRecord.fetch(slashRecord);
+    expect(record.slashedAmount.toNumber()).to.equal(2 * slashed);
+  });
+
+  it("rejects thresholds larger than the number of named verifiers", async () => {
+    const verifier = await initializeVerifier(bodyAuthority, "Threshold Verifier");
+    const coVerifier = await initializeVerifier(bodyAuthority, "Threshold Co-Verifier");
+    await expectError(
+      submitRequest(verifier, accreditationBody, requester, { forestry: {} }, 1000, undefined, [coVerifier], 3),
+      "InvalidSubmissionThreshold"
+    );
+    await expectError(
+      submitRequest(verifier, accreditationBody, requester, { forestry: {} }, 1000, undefined, [verifier], 1),
+      "DuplicateVerifier"
+    );
+  });
+
+  describe("request lifecycle", () => {
+    async function openRequest(verifiers: PublicKey[], requiredSubmissions = 1) {
+      const project = await createProject({ forestry: {} });
+      await submitRequest(
+        verifiers[0],
+        accreditationBody,
+        requester,
+        { forestry: {} },
+        1000,
+        project,
+        verifiers.slice(1),
+        requiredSubmissions
+      );
+      return requestPda(project, requester.publicKey);
+    }
+
+    const respond = (
+      method: "declineRequest" | "rejectVerification",
+      verificationRequest: PublicKey,
+      verifier: PublicKey
+    ) =>
+      program.methods[method]("outside our current capacity")
+        .accounts({
+          verificationRequest,
+          verifier,
+          feeVault: null,
+          verifierTokenAccount: null,
+          requesterTokenAccount: null,
+          requester: null,
+          verifierAuthority: payer.publicKey,
+          tokenProgram: null,
+        })
+        .rpc();
+
+    it("moves to InProgress when a verifier accepts with a quote and deadline", async () => {
+      const verifier = await initializeVerifier(bodyAuthority, "Quoting Verifier");
+      const verificationRequest = await openRequest([verifier]);
+
+      await expectError(conductVerification(verificationRequest, verifier), "RequestNotOpen");
+      await expectError(acceptRequest(verificationRequest, verifier, 5000, now() - 60), "InvalidDeadline");
+      const deadline = now() + 30 * 24 * 60 * 60;
+      await acceptRequest(verificationRequest, verifier, 5000, deadline);
+      await expectError(acceptRequest(verificationRequest, verifier), "AlreadyAccepted");
+
+      const request = await program.account.verificationRequest.fetch(verificationRequest);
+      expect(request.status).to.deep.equal({ inProgress: {} });
+      expect(request.acceptances[0].feeQuote.toNumber()).to.equal(5000);
+      expect(request.acceptances[0].deadline.toNumber()).to.equal(deadline);
+    });
+
+    it("declines a request once too few verifiers remain", async () => {
+      const verifiers = [
+        await initializeVerifier(bodyAuthority, "Declining Lead"),
+        await initializeVerifier(bodyAuthority, "Declining Co-Verifier"),
+      ];
+      const verificationRequest = await openRequest(verifiers, 1);
+
+      await acceptRequest(verificationRequest, verifiers[0]);
+      await respond("declineRequest", verificationRequest, verifiers[0]);
+      let request = await program.account.verificationRequest.fetch(verificationRequest);
+      expect(request.status).to.deep.equal({ pending: {} });
+      expect(request.verifiers.map((key) => key.toBase58())).to.deep.equal(verifiers.map((key) => key.toBase58()));
+      expect(request.declines.map((key) => key.toBase58())).to.deep.equal([verifiers[0].toBase58()]);
+      await expectError(acceptRequest(verificationRequest, verifiers[0]), "VerifierDeclined");
+      await expectError(respond("declineRequest", verificationRequest, verifiers[0]), "VerifierDeclined");
+
+      await respond("declineRequest", verificationRequest, verifiers[1]);
+      request = await program.account.verificationRequest.fetch(verificationRequest);
+      expect(request.status).to.deep.equal({ declined: {} });
+    });
+
+    it("records the findings of a rejected verification", async () => {
+      const verifier = await initializeVerifier(bodyAuthority, "Rejecting Verifier");
+      const verificationRequest = await openRequest([verifier]);
+
+      await expectError(respond("rejectVerification", verificationRequest, verifier), "RequestNotOpen");
+      await acceptRequest(verificationRequest, verifier);
+      await respond("rejectVerification", verificationRequest, verifier);
+
+      const request = await program.account.verificationRequest.fetch(verificationRequest);
+      expect(request.status).to.deep.equal({ rejected: {} });
+      expect(request.findings).to.equal("outside our current capacity");
+      await expectError(conductVerification(verificationRequest, verifier), "RequestNotOpen");
+    });
+
+    it("lets only the requester withdraw an open request", async () => {
+      const verifier = await initializeVerifier(bodyAuthority, "Withdrawn Verifier");
+      const verificationRequest = await openRequest([verifier]);
+      await acceptRequest(verificationRequest, verifier);
+
+      await expectError(
+        program.methods
+          .withdrawRequest()
+          .accounts({
+            verificationRequest,
+            feeVault: null,
+            requesterTokenAccount: null,
+            requester: outsider.publicKey,
+            tokenProgram: null,
+          })
+          .signers([outsider])
+          .rpc(),
+        "ConstraintHasOne"
+      );
+      await program.methods
+        .withdrawRequest()
+        .accounts({
+          verificationRequest,
+          feeVault: null,
+          requesterTokenAccount: null,
+          requester: requester.publicKey,
+          tokenProgram: null,
+        })
+        .signers([requester])
+        .rpc();
+
+      const request = await program.account.verificationRequest.fetch(verificationRequest);
+      expect(request.status).to.deep.equal({ withdrawn: {} });
+      await expectError(acceptRequest(verificationRequest, verifier), "RequestNotOpen");
+    });
+  });
+
+  describe("escrowed fees", () => {
+    const FEE = 10_000;
+    let feeMint: PublicKey;
+    let requesterTokenAccount: PublicKey;
+    let verifierTokenAccount: PublicKey;
+
+    const balance = async (tokenAccount: PublicKey) => Number((await getAccount(connection, tokenAccount)).amount);
+
+    before(async () => {
+      feeMint = await createMint(connection, payer, payer.publicKey, null, 6);
+      requesterTokenAccount = await createAccount(connection, payer, feeMint, requester.publicKey, Keypair.generate());
+      verifierTokenAccount = await createAccount(connection, payer, feeMint, payer.publicKey, Keypair.generate());
+      await mintTo(connection, payer, feeMint, requesterTokenAccount, payer, 10 * FEE);
+    });
+
+    async function openFeeRequest(verifier: PublicKey) {
+      const project = await createProject({ forestry: {} });
+      const fee = { amount: FEE, mint: feeMint, requesterTokenAccount };
+      await submitRequest(verifier, accreditationBody, requester, undefined, 1000, project, [], 1, undefined, fee);
+      return requestPda(project, requester.publicKey);
+    }
+
+    const closeRequest = (method: "withdrawRequest" | "expireRequest", verificationRequest: PublicKey) =>
+      program.methods[method]()
+        .accounts({
+          verificationRequest,
+          feeVault: feeVaultPda(verificationRequest),
+          requesterTokenAccount,
+          requester: requester.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([requester])
+        .rpc();
+
+    it("releases the fee less the holdback when the verification is conducted", async () => {
+      const verifier = await initializeVerifier(bodyAuthority, "Paid Verifier");
+      const verificationRequest = await openFeeRequest(verifier);
+      expect(await balance(feeVaultPda(verificationRequest))).to.equal(FEE);
+
+      const config = await program.account.verificationConfig.fetch(configPda);
+      const heldBack = (FEE * config.feeHoldbackBps) / 10000;
+      const verifierBefore = await balance(verifierTokenAccount);
+      await acceptRequest(verificationRequest, verifier);
+      await expectError(conductVerification(verificationRequest, verifier), "FeeAccountsRequired");
+      const verificationResult = resultPda(verificationRequest);
+      await conductVerification(verificationRequest, verifier, 1000, 85, verificationResult, verifierTokenAccount);
+
+      expect(await balance(verifierTokenAccount)).to.equal(verifierBefore + FEE - heldBack);
+      expect(await balance(feeVaultPda(verificationRequest))).to.equal(heldBack);
+      await expectError(
+        program.methods
+          .claimFeeHoldback()
+          .accounts({
+            verificationRequest,
+            verificationResult,
+            verifier,
+            feeVault: feeVaultPda(verificationRequest),
+            verifierTokenAccount,
+            requester: requester.publicKey,
+            verifierAuthority: payer.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc(),
+        "FeeHoldbackActive"
+      );
+    });
+
+    it("refunds the escrow when the request is withdrawn", async () => {
+      const verifier = await initializeVerifier(bodyAuthority, "Unpaid Verifier");
+      const requesterBefore = await balance(requesterTokenAccount);
+      const verificationRequest = await openFeeRequest(verifier);
+      await closeRequest("withdrawRequest", verificationRequest);
+
+      expect(await balance(requesterTokenAccount)).to.equal(requesterBefore);
+      expect(await connection.getAccountInfo(feeVaultPda(verificationRequest))).to.equal(null);
+    });
+
+    it("refunds the escrow once an accepted verifier misses its deadline", async () => {
+      const verifier = await initializeVerifier(bodyAuthority, "Late Verifier");
+      const requesterBefore = await balance(requesterTokenAccount);
+      const verificationRequest = await openFeeRequest(verifier);
+      const deadline = now() + 2;
+      await acceptRequest(verificationRequest, verifier, FEE, deadline);
+
+      await expectError(closeRequest("expireRequest", verificationRequest), "DeadlineNotPassed");
+      await waitUntil(deadline);
+      await expectError(conductVerification(verificationRequest, verifier), "DeadlinePassed");
+      await closeRequest("expireRequest", verificationRequest);
+
+      const request = await program.account.verificationRequest.fetch(verificationRequest);
+      expect(request.status).to.deep.equal({ expired: {} });
+      expect(await balance(requesterTokenAccount)).to.equal(requesterBefore);
+    });
+
+    it("claws the holdback back to the requester when a challenge is upheld", async () => {
+      const verifier = await initializeVerifier(bodyAuthority, "Clawed Back Verifier");
+      const verificationRequest = await openFeeRequest(verifier);
+      const verificationResult = resultPda(verificationRequest);
+      await acceptRequest(verificationRequest, verifier);
+      await conductVerification(verificationRequest, verifier, 1000, 85, verificationResult, verifierTokenAccount);
+      const heldBack = await balance(feeVaultPda(verificationRequest));
+
+      const challenge = await challengeVerification(verificationResult, outsider);
+      await castVote(challenge, verificationResult, verifier, arbiters[0], { upheld: {} }).rpc();
+      await castVote(challenge, verificationResult, verifier, arbiters[1], { upheld: {} }).rpc();
+      await waitForAppealDeadline(challenge);
+
+      const requesterBefore = await balance(requesterTokenAccount);
+      await settleChallenge(challenge, verificationResult, verifier, { requesterTokenAccount });
+      expect(await balance(requesterTokenAccount)).to.equal(requesterBefore + heldBack);
+      expect(await connection.getAccountInfo(feeVaultPda(verificationRequest))).to.equal(null);
+    });
+
+    it("pays a rejecting co-verifier its share without paying the submitter twice", async () => {
+      const coAuthority = await fundedKeypair();
+      const lead = await initializeVerifier(bodyAuthority, "Submitting Verifier");
+      const coVerifier = await initializeVerifier(bodyAuthority, "Rejecting Co-Verifier", coAuthority);
+      const coTokenAccount = await createAccount(connection, payer, feeMint, coAuthority.publicKey, Keypair.generate());
+      const project = await createProject({ forestry: {} });
+      const fee = { amount: FEE, mint: feeMint, requesterTokenAccount };
+      await submitRequest(lead, accreditationBody, requester, undefined, 1000, project, [coVerifier], 2, undefined, fee);
+      const verificationRequest = requestPda(project, requester.publicKey);
+      const feeVault = feeVaultPda(verificationRequest);
+
+      await acceptRequest(verificationRequest, lead);
+      await acceptRequest(verificationRequest, coVerifier, null, null, coAuthority);
+      await conductVerification(verificationRequest, lead, 1000, 85, null, verifierTokenAccount);
+
+      const rejectVerification = (verifier: PublicKey, tokenAccount: PublicKey, authority: Keypair) =>
+        program.methods
+          .rejectVerification("baseline not supported by monitoring data")
+          .accounts({
+            verificationRequest,
+            verifier,
+            feeVault,
+            verifierTokenAccount: tokenAccount,
+            requesterTokenAccount,
+            requester: requester.publicKey,
+            verifierAuthority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers(authority === payer ? [] : [authority])
+          .rpc();
+      await expectError(rejectVerification(lead, verifierTokenAccount, payer), "AlreadySubmitted");
+
+      const { feeHoldbackBps } = await program.account.verificationConfig.fetch(configPda);
+      const heldBack = ((FEE / 2) * feeHoldbackBps) / 10000;
+      const verifierBefore = await balance(verifierTokenAccount);
+      const requesterBefore = await balance(requesterTokenAccount);
+      await rejectVerification(coVerifier, coTokenAccount, coAuthority);
+
+      // Each share is paid once; only the submitter's holdback is left in the vault
+      expect(await balance(coTokenAccount)).to.equal(FEE / 2);
+      expect(await balance(verifierTokenAccount)).to.equal(verifierBefore);
+      expect(await balance(requesterTokenAccount)).to.equal(requesterBefore);
+      expect(await balance(feeVault)).to.equal(heldBack);
+
+      await program.methods
+        .claimFeeHoldback()
+        .accounts({
+          verificationRequest,
+          verificationResult: null,
+          verifier: lead,
+          feeVault,
+          verifierTokenAccount,
+          requester: requester.publicKey,
+          verifierAuthority: payer.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+      expect(await balance(verifierTokenAccount)).to.equal(verifierBefore + heldBack);
+      expect(await connection.getAccountInfo(feeVault)).to.equal(null);
+    });
+  });
+});
\ No newline at end of file